@@ -0,0 +1,67 @@
+use classify::JenksConfig;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+// Compare implementations by saving a baseline before a change (`cargo bench -- --save-baseline before`) and comparing against it after (`cargo bench -- --baseline before`)
+fn jenks_100k(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let data: Vec<f64> = (0..100_000).map(|_| rng.gen_range(0.0..1000.0)).collect();
+    let config = JenksConfig::new().permutations(100);
+
+    c.bench_function("jenks 100k points, 8 bins, 100 permutations", |b| {
+        b.iter(|| config.breaks(8, black_box(&data)))
+    });
+
+    c.bench_function("direct gvf 100k points, 8 bins, 100 permutations", |b| {
+        b.iter(|| direct_jenks_breaks(8, 100, black_box(&data)))
+    });
+}
+
+// The sampling loop Jenks ran before prefix sums, recomputing every bin's sum of squared deviations for each permutation
+fn direct_jenks_breaks(num_bins: usize, permutations: usize, data: &[f64]) -> Vec<usize> {
+    let mut sorted_data = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let gssd = direct_ssd(&sorted_data);
+
+    let mut rng = StdRng::seed_from_u64(123456789);
+    let mut best_breaks = vec![0_usize; num_bins - 1];
+    let mut max_gvf = 0.0;
+    for _ in 0..permutations {
+        let mut breaks: Vec<usize> =
+            rand::seq::index::sample(&mut rng, sorted_data.len() - 1, num_bins - 1)
+                .into_iter()
+                .map(|idx| idx + 1)
+                .collect();
+        breaks.sort_unstable();
+        let gvf = direct_gvf(&breaks, &sorted_data, gssd);
+        if gvf > max_gvf {
+            max_gvf = gvf;
+            best_breaks = breaks;
+        }
+    }
+    best_breaks
+}
+
+fn direct_gvf(breaks: &[usize], vals: &[f64], gssd: f64) -> f64 {
+    let mut bounds = vec![0];
+    bounds.extend_from_slice(breaks);
+    bounds.push(vals.len());
+    let tssd: f64 = bounds
+        .windows(2)
+        .map(|bin| direct_ssd(&vals[bin[0]..bin[1]]))
+        .sum();
+    1.0 - tssd / gssd
+}
+
+fn direct_ssd(vals: &[f64]) -> f64 {
+    let mean = vals.iter().sum::<f64>() / vals.len() as f64;
+    vals.iter().map(|val| (val - mean) * (val - mean)).sum()
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = jenks_100k
+}
+criterion_main!(benches);
@@ -0,0 +1,136 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+use num_traits::ToPrimitive;
+
+/// Returns a Classification object following the Arithmetic Interval Breaks algorithm given the desired number of bins and one-dimensional data
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_arithmetic_interval_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f32> = vec![0.0, 0.5, 1.0, 2.0, 2.5, 3.5, 4.0, 6.0];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_arithmetic_interval_classification(num_bins, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 0.0, bin_end: 1.0, count: 2},
+///     Bin{bin_start: 1.0, bin_end: 3.0, count: 3},
+///     Bin{bin_start: 3.0, bin_end: 6.0, count: 3}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_arithmetic_interval_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Classification {
+    let breaks: Vec<f64> = get_arithmetic_interval_breaks(num_bins, data);
+    breaks_to_classification(&breaks, data)
+}
+
+/// Returns a Classification object following the Arithmetic Interval algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_arithmetic_interval_classification, try_get_arithmetic_interval_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_arithmetic_interval_classification(3, &data).unwrap() == get_arithmetic_interval_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_arithmetic_interval_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_arithmetic_interval_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_arithmetic_interval_classification(num_bins, data))
+}
+
+/// Returns a vector of breaks generated through the Arithmetic Interval Breaks algorithm given the desired number of bins and a dataset
+///
+/// The bin widths form an arithmetic progression of the form d, 2d, 3d, ..., so that each bin is wider than the one before it by the width of the first bin, with d chosen so that the widths sum to the data's range (d = 2 * range / (num_bins * (num_bins + 1)))
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_arithmetic_interval_breaks;
+///
+/// let data: Vec<u32> = vec![0, 1, 2, 3, 5, 8, 10];
+/// let num_bins = 4;
+///
+/// let result: Vec<f64> = get_arithmetic_interval_breaks(num_bins, &data);
+///
+/// assert_eq!(result, vec![1.0, 3.0, 6.0]);
+/// ```
+pub fn get_arithmetic_interval_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+    let data = to_vec_f64(data);
+
+    let mut min_value = data[0];
+    let mut max_value = data[0];
+    for item in data {
+        if item < min_value {
+            min_value = item;
+        } else if item > max_value {
+            max_value = item;
+        }
+    }
+
+    let mut result: Vec<f64> = vec![];
+    if min_value == max_value {
+        return result;
+    }
+
+    let num_bins_f64 = num_bins as f64;
+    let step = 2.0 * (max_value - min_value) / (num_bins_f64 * (num_bins_f64 + 1.0));
+
+    // The ith break lies after the first i bins, whose widths sum to step * i * (i + 1) / 2
+    for i in 1..num_bins {
+        let i = i as f64;
+        result.push(min_value + step * i * (i + 1.0) / 2.0);
+    }
+
+    result
+}
@@ -0,0 +1,177 @@
+use alloc::borrow::Cow;
+
+use ndarray::ArrayView1;
+
+use crate::error::ClassifyError;
+use crate::percentile::PercentileInterpolation;
+use crate::utilities::Classification;
+
+/// Borrows the data of an array view as a slice when it is contiguous, copying it only for strided views
+fn view_to_slice<'a>(data: &'a ArrayView1<f64>) -> Cow<'a, [f64]> {
+    match data.as_slice() {
+        Some(slice) => Cow::Borrowed(slice),
+        None => Cow::Owned(data.iter().copied().collect()),
+    }
+}
+
+/// Returns a Classification object following the Jenks Natural Breaks algorithm for a one-dimensional ndarray view, see get_jenks_classification
+///
+/// Contiguous views are classified without copying their data into a vector first. The other classification methods have equivalent `_ndarray` functions
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, get_jenks_classification_ndarray};
+/// use ndarray::Array1;
+///
+/// let data: Array1<f64> = Array1::from(vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0]);
+///
+/// assert_eq!(get_jenks_classification_ndarray(3, &data.view()), get_jenks_classification(3, &data.to_vec()));
+/// ```
+pub fn get_jenks_classification_ndarray(num_bins: usize, data: &ArrayView1<f64>) -> Classification {
+    crate::jenks::get_jenks_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Quantile Breaks algorithm for a one-dimensional ndarray view, see get_quantile_classification
+pub fn get_quantile_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::quantile::get_quantile_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Head-Tail Breaks algorithm for a one-dimensional ndarray view, see get_head_tail_classification
+pub fn get_head_tail_classification_ndarray(data: &ArrayView1<f64>) -> Classification {
+    crate::head_tail::get_head_tail_classification(&view_to_slice(data))
+}
+
+/// Returns a Classification object following the Equal Interval algorithm for a one-dimensional ndarray view, see get_equal_interval_classification
+pub fn get_equal_interval_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::equal_interval::get_equal_interval_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Standard Deviation algorithm for a one-dimensional ndarray view, see get_st_dev_classification
+pub fn get_st_dev_classification_ndarray(bin_size: f64, data: &ArrayView1<f64>) -> Classification {
+    crate::standard_deviation::get_st_dev_classification(bin_size, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Hinge algorithm for a one-dimensional ndarray view, see get_hinge_classification
+pub fn get_hinge_classification_ndarray(
+    hinge_coefficient: f64,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::hinge::get_hinge_classification(hinge_coefficient, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Box Plot algorithm for a one-dimensional ndarray view, see get_boxplot_classification
+pub fn get_boxplot_classification_ndarray(data: &ArrayView1<f64>) -> Classification {
+    crate::hinge::get_boxplot_classification(&view_to_slice(data))
+}
+
+/// Returns a Classification object following the Geometric Interval algorithm for a one-dimensional ndarray view, see get_geometric_interval_classification
+pub fn get_geometric_interval_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::geometric_interval::get_geometric_interval_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Arithmetic Interval algorithm for a one-dimensional ndarray view, see get_arithmetic_interval_classification
+pub fn get_arithmetic_interval_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::arithmetic_interval::get_arithmetic_interval_classification(
+        num_bins,
+        &view_to_slice(data),
+    )
+}
+
+/// Returns a Classification object following the Fisher-Jenks algorithm for a one-dimensional ndarray view, see get_fisher_jenks_classification
+pub fn get_fisher_jenks_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::fisher_jenks::get_fisher_jenks_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Maximum Breaks algorithm for a one-dimensional ndarray view, see get_maximum_breaks_classification
+pub fn get_maximum_breaks_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::maximum_breaks::get_maximum_breaks_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Pretty Breaks algorithm for a one-dimensional ndarray view, see get_pretty_classification
+pub fn get_pretty_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::pretty::get_pretty_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the k-means algorithm for a one-dimensional ndarray view, see get_kmeans_classification
+pub fn get_kmeans_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+    max_iter: usize,
+) -> Classification {
+    crate::kmeans::get_kmeans_classification(num_bins, &view_to_slice(data), max_iter)
+}
+
+/// Returns a Classification object following the Percentile algorithm for a one-dimensional ndarray view, see get_percentile_classification
+pub fn get_percentile_classification_ndarray(
+    percentiles: &[f64],
+    data: &ArrayView1<f64>,
+    interpolation: PercentileInterpolation,
+) -> Result<Classification, ClassifyError> {
+    crate::percentile::get_percentile_classification(
+        percentiles,
+        &view_to_slice(data),
+        interpolation,
+    )
+}
+
+/// Returns a Classification object following the Logarithmic Interval algorithm for a one-dimensional ndarray view, see get_logarithmic_classification
+pub fn get_logarithmic_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Result<Classification, ClassifyError> {
+    crate::logarithmic::get_logarithmic_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Unique Value algorithm for a one-dimensional ndarray view, see get_unique_value_classification
+pub fn get_unique_value_classification_ndarray(
+    data: &ArrayView1<f64>,
+    max_classes: Option<usize>,
+) -> Classification {
+    crate::unique_value::get_unique_value_classification(&view_to_slice(data), max_classes)
+}
+
+/// Returns a Classification object following the Ckmeans algorithm for a one-dimensional ndarray view, see get_ckmeans_classification
+pub fn get_ckmeans_classification_ndarray(
+    num_bins: usize,
+    data: &ArrayView1<f64>,
+) -> Classification {
+    crate::ckmeans::get_ckmeans_classification(num_bins, &view_to_slice(data))
+}
+
+/// Returns a Classification object following the Defined Interval algorithm for a one-dimensional ndarray view, see get_defined_interval_classification
+pub fn get_defined_interval_classification_ndarray(
+    interval: f64,
+    data: &ArrayView1<f64>,
+) -> Result<Classification, ClassifyError> {
+    crate::defined_interval::get_defined_interval_classification(interval, &view_to_slice(data))
+}
+
+/// Returns a Classification object following manually defined breaks for a one-dimensional ndarray view, see get_manual_classification
+pub fn get_manual_classification_ndarray(
+    breaks: &[f64],
+    data: &ArrayView1<f64>,
+) -> Result<Classification, ClassifyError> {
+    crate::manual::get_manual_classification(breaks, &view_to_slice(data))
+}
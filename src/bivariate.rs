@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+use crate::classification::ClassificationExt;
+use crate::error::ClassifyError;
+use crate::method::ClassificationMethod;
+use crate::utilities::Classification;
+
+/// Represents the classification of two variables measured on the same observations, as used for bivariate choropleth maps
+#[derive(Debug, Clone, PartialEq)]
+pub struct BivariateClassification {
+    /// The Classification of the first variable
+    pub x: Classification,
+    /// The Classification of the second variable
+    pub y: Classification,
+    /// The pair of bin indices (in x and in y) of each observation, in the order of the data
+    pub classes: Vec<(usize, usize)>,
+}
+
+/// Returns a BivariateClassification of two variables, each classified independently through the same method, along with the pair of bins each observation falls in
+///
+/// # Arguments
+///
+/// * `num_bins_x` - An integer (usize) representing the desired number of bins for the first variable
+/// * `num_bins_y` - An integer (usize) representing the desired number of bins for the second variable
+/// * `x` - A reference to a collection of unsorted values of the first variable, one per observation
+/// * `y` - A reference to a collection of unsorted values of the second variable, one per observation
+/// * `method` - The ClassificationMethod used for both variables
+///
+/// # Edge cases
+///
+/// * Returns ClassifyError::MismatchedLengths if x and y hold different numbers of values
+/// * Returns any error the method produces for either variable (e.g. ClassifyError::EmptyData or ClassifyError::NonFinite)
+/// * With methods that take no number of bins, or that produce fewer bins than requested, the number of bins of each variable may differ from the one asked for
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_bivariate_classification, BivariateClassification};
+/// use classify::ClassificationMethod;
+///
+/// let income: Vec<f64> = vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0];
+/// let density: Vec<f64> = vec![600.0, 500.0, 100.0, 200.0, 300.0, 400.0];
+///
+/// let result: BivariateClassification = get_bivariate_classification(3, 3, &income, &density, &ClassificationMethod::Quantile).unwrap();
+///
+/// assert_eq!((result.x.len(), result.y.len()), (3, 3));
+/// assert_eq!(result.classes, vec![(0, 2), (0, 2), (1, 0), (1, 0), (2, 1), (2, 1)]);
+/// ```
+pub fn get_bivariate_classification(
+    num_bins_x: usize,
+    num_bins_y: usize,
+    x: &[f64],
+    y: &[f64],
+    method: &ClassificationMethod,
+) -> Result<BivariateClassification, ClassifyError> {
+    if x.len() != y.len() {
+        return Err(ClassifyError::MismatchedLengths);
+    }
+
+    let x_classification = method.classify(num_bins_x, x)?;
+    let y_classification = method.classify(num_bins_y, y)?;
+
+    let x_classes = x_classification.assign_all(x, None);
+    let y_classes = y_classification.assign_all(y, None);
+    let classes: Vec<(usize, usize)> = x_classes.into_iter().zip(y_classes).collect();
+
+    Ok(BivariateClassification {
+        x: x_classification,
+        y: y_classification,
+        classes,
+    })
+}
@@ -0,0 +1,261 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::ToPrimitive;
+
+use crate::error::ClassifyError;
+use crate::utilities::{breaks_to_classification, create_unique_val_mapping, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+use crate::utilities::{Classification, UniqueVal, UniqueValSums};
+
+/// Returns a Classification object following the Ckmeans.1d.dp algorithm given the desired number of bins and one-dimensional data
+///
+/// Ckmeans finds the globally optimal clustering (minimizing the within-class sum of squared deviations) like get_fisher_jenks_classification, but uses a divide-and-conquer search that takes O(k * n * log(n)) time for k bins and n unique values
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_ckmeans_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<usize> = vec![1, 2, 4, 5, 7, 8];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_ckmeans_classification(num_bins, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 7.0, count: 2},
+///     Bin{bin_start: 7.0, bin_end: 8.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_ckmeans_classification<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Classification {
+    let (breaks, _ssd) = ckmeans_breaks_and_ssd(num_bins, data);
+    breaks_to_classification(&breaks, data)
+}
+
+/// Returns a Classification object following the Ckmeans.1d.dp algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_ckmeans_classification, try_get_ckmeans_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_ckmeans_classification(3, &data).unwrap() == get_ckmeans_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_ckmeans_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_ckmeans_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_ckmeans_classification(num_bins, data))
+}
+
+/// Returns a Classification object following the Ckmeans.1d.dp algorithm along with the total within-class sum of squared deviations it achieves
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_ckmeans_classification_with_ssd;
+///
+/// let data: Vec<usize> = vec![1, 2, 4, 5, 7, 8];
+///
+/// let (result, ssd) = get_ckmeans_classification_with_ssd(3, &data);
+///
+/// assert_eq!(result.len(), 3);
+/// assert_eq!(ssd, 1.5);
+/// ```
+pub fn get_ckmeans_classification_with_ssd<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> (Classification, f64) {
+    let (breaks, ssd) = ckmeans_breaks_and_ssd(num_bins, data);
+    (breaks_to_classification(&breaks, data), ssd)
+}
+
+/// Returns a vector of breaks generated through the Ckmeans.1d.dp algorithm given the desired number of bins and a dataset
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_ckmeans_breaks;
+///
+/// let data: Vec<i8> = vec![1, 2, 4, 5, 7, 8];
+/// let num_bins = 3;
+///
+/// let result: Vec<f64> = get_ckmeans_breaks(num_bins, &data);
+///
+/// assert_eq!(result, vec![4.0, 7.0]);
+/// ```
+pub fn get_ckmeans_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+    let (breaks, _ssd) = ckmeans_breaks_and_ssd(num_bins, data);
+    breaks
+}
+
+/// Generates Ckmeans breaks and returns them along with the total within-class sum of squared deviations
+fn ckmeans_breaks_and_ssd<T: ToPrimitive>(num_bins: usize, data: &[T]) -> (Vec<f64>, f64) {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+    let sums = UniqueValSums::new(&unique_val_map);
+
+    let num_unique_vals = unique_val_map.len();
+    let true_num_bins = core::cmp::min(num_unique_vals, num_bins);
+    if true_num_bins <= 1 {
+        return (vec![], sums.ssd(0, num_unique_vals));
+    }
+
+    let (unique_breaks, ssd) = ckmeans_unique_breaks(true_num_bins, &sums, num_unique_vals);
+
+    let mut breaks: Vec<f64> = vec![];
+    for idx in unique_breaks {
+        breaks.push(unique_val_map[idx].val);
+    }
+
+    (breaks, ssd)
+}
+
+/// Finds the unique value indices at which each bin (after the first) starts such that the within-class sum of squared deviations is minimized, returning them along with the minimal sum
+///
+/// # Arguments
+///
+/// * `num_bins` - The number of bins (at least 2 and at most the number of unique values)
+/// * `sums` - A reference to the cumulative sums of the unique values
+/// * `num_unique_vals` - The number of unique values
+pub fn ckmeans_unique_breaks(
+    num_bins: usize,
+    sums: &UniqueValSums,
+    num_unique_vals: usize,
+) -> (Vec<usize>, f64) {
+    // costs[j] holds the minimal SSD of splitting the first j + 1 unique values into the current number of bins
+    let mut costs: Vec<f64> = (0..num_unique_vals).map(|j| sums.ssd(0, j + 1)).collect();
+    let mut starts: Vec<Vec<usize>> = vec![vec![0; num_unique_vals]; num_bins];
+
+    for (bin, bin_starts) in starts.iter_mut().enumerate().skip(1) {
+        let mut new_costs: Vec<f64> = vec![f64::INFINITY; num_unique_vals];
+        fill_row(
+            bin,
+            num_unique_vals - 1,
+            bin,
+            num_unique_vals - 1,
+            sums,
+            &costs,
+            &mut new_costs,
+            bin_starts,
+        );
+        costs = new_costs;
+    }
+
+    let mut breaks: Vec<usize> = vec![0; num_bins - 1];
+    let mut end = num_unique_vals - 1;
+    for bin in (1..num_bins).rev() {
+        let start = starts[bin][end];
+        breaks[bin - 1] = start;
+        end = start - 1;
+    }
+
+    (breaks, costs[num_unique_vals - 1])
+}
+
+/// Fills new_costs and bin_starts for the last unique values lower..=upper of a bin, relying on the optimal start of the bin being non-decreasing in its last value so that only starts in start_lower..=start_upper need to be searched
+#[allow(clippy::too_many_arguments)]
+fn fill_row(
+    lower: usize,
+    upper: usize,
+    start_lower: usize,
+    start_upper: usize,
+    sums: &UniqueValSums,
+    prev_costs: &[f64],
+    new_costs: &mut [f64],
+    bin_starts: &mut [usize],
+) {
+    if lower > upper {
+        return;
+    }
+
+    let mid = (lower + upper) / 2;
+    let mut best_start = start_lower;
+    for start in start_lower..=core::cmp::min(mid, start_upper) {
+        let cost = prev_costs[start - 1] + sums.ssd(start, mid + 1);
+        if cost < new_costs[mid] {
+            new_costs[mid] = cost;
+            best_start = start;
+        }
+    }
+    bin_starts[mid] = best_start;
+
+    if mid > lower {
+        fill_row(
+            lower,
+            mid - 1,
+            start_lower,
+            best_start,
+            sums,
+            prev_costs,
+            new_costs,
+            bin_starts,
+        );
+    }
+    fill_row(
+        mid + 1,
+        upper,
+        best_start,
+        start_upper,
+        sums,
+        prev_costs,
+        new_costs,
+        bin_starts,
+    );
+}
@@ -0,0 +1,916 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+use num_traits::ToPrimitive;
+
+#[cfg(feature = "colors")]
+use crate::colors::assign_colors;
+use crate::error::ClassifyError;
+use crate::utilities::{breaks_to_classification, to_vec_f64, Classification};
+
+/// Represents the granularity that ClassificationExt::snap_breaks rounds breaks to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SnapMode {
+    /// Round to the given number of decimal places (negative values round to tens, hundreds, etc.)
+    Decimals(i32),
+    /// Round to multiples of the largest step of the form 1, 2, or 5 times a power of 10 that is smaller than the narrowest bin, so that no two bounds collapse
+    Nice,
+}
+
+/// Represents the formatting of the labels produced by ClassificationExt::legend_labels
+///
+/// # Examples
+///
+/// ```
+/// use classify::LabelOptions;
+///
+/// let opts = LabelOptions::new().precision(1).thousands_separator(',').prefix("$");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelOptions {
+    precision: usize,
+    thousands_separator: Option<char>,
+    prefix: String,
+    suffix: String,
+    open_ends: bool,
+}
+
+impl Default for LabelOptions {
+    fn default() -> Self {
+        LabelOptions {
+            precision: 2,
+            thousands_separator: None,
+            prefix: String::new(),
+            suffix: String::new(),
+            open_ends: false,
+        }
+    }
+}
+
+impl LabelOptions {
+    /// Creates label options with the default settings: two decimal places, no thousands separator, no prefix or suffix, and closed outer bins
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of decimal places each bound is rounded to (defaults to 2)
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the character inserted between each group of three digits in the integer part of each bound (defaults to none)
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Sets the text placed before each bound, such as "$" (defaults to none)
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Sets the text placed after each bound, such as "%" (defaults to none)
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Sets whether the first bin is labeled "< x" with its end and the last bin "> y" with its start (defaults to false); bins with infinite bounds are always labeled this way
+    pub fn open_ends(mut self, open_ends: bool) -> Self {
+        self.open_ends = open_ends;
+        self
+    }
+
+    /// Formats a single bound following these options
+    fn format_bound(&self, val: f64) -> String {
+        let mut formatted = format!("{:.*}", self.precision, val);
+        if formatted.starts_with('-') && formatted[1..].chars().all(|c| c == '0' || c == '.') {
+            // Values that round to zero should not keep their sign
+            formatted.remove(0);
+        }
+
+        if let Some(separator) = self.thousands_separator {
+            let (sign, unsigned) = match formatted.strip_prefix('-') {
+                Some(unsigned) => ("-", unsigned),
+                None => ("", formatted.as_str()),
+            };
+            let (int_part, frac_part) = match unsigned.find('.') {
+                Some(idx) => unsigned.split_at(idx),
+                None => (unsigned, ""),
+            };
+            if int_part.chars().all(|c| c.is_ascii_digit()) {
+                let mut grouped = String::new();
+                for (i, digit) in int_part.chars().enumerate() {
+                    if i > 0 && (int_part.len() - i) % 3 == 0 {
+                        grouped.push(separator);
+                    }
+                    grouped.push(digit);
+                }
+                formatted = format!("{}{}{}", sign, grouped, frac_part);
+            }
+        }
+
+        format!("{}{}{}", self.prefix, formatted, self.suffix)
+    }
+}
+
+/// Adds methods for working with an existing Classification, such as applying it to new data
+///
+/// Classification is a plain vector of Bin objects, so these methods are provided through this trait, which needs to be in scope to use them
+pub trait ClassificationExt {
+    /// Returns the index of the Bin within which a value falls, or None if the value lies outside of the Classification's range
+    ///
+    /// Each bin contains values from its start up to but excluding its end, except for the highest bin, which also contains its end. The bins are binary searched, so they must be sorted and contiguous, as produced by every classification function, either in ascending order or in the descending order produced by reversed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 5},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 5},
+    ///     Bin{bin_start: 2.0, bin_end: 3.0, count: 5}
+    /// ];
+    ///
+    /// assert_eq!(class.classify(1.0), Some(1));
+    /// assert_eq!(class.classify(3.0), Some(2));
+    /// assert_eq!(class.classify(3.5), None);
+    /// ```
+    fn classify(&self, value: f64) -> Option<usize>;
+
+    /// Returns the index of the Bin within which a value falls, assigning values below the Classification's range to the lowest bin and values above it to the highest bin (the first and last bins unless the Classification is reversed)
+    ///
+    /// Returns None only if the Classification has no bins or the value is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 5},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 5}
+    /// ];
+    ///
+    /// assert_eq!(class.classify_clamped(-4.0), Some(0));
+    /// assert_eq!(class.classify_clamped(0.5), Some(0));
+    /// assert_eq!(class.classify_clamped(9.0), Some(1));
+    /// ```
+    fn classify_clamped(&self, value: f64) -> Option<usize>;
+
+    /// Returns the palette color of the Bin within which a value falls (see assign_colors for how bins map onto the palette), or None if the value lies outside of the Classification's range or the palette is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 5},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 5}
+    /// ];
+    /// let palette: Vec<[u8; 3]> = vec![[255, 255, 204], [0, 104, 55]];
+    ///
+    /// assert_eq!(class.color_for_value(1.5, &palette), Some([0, 104, 55]));
+    /// assert_eq!(class.color_for_value(4.0, &palette), None);
+    /// ```
+    #[cfg(feature = "colors")]
+    fn color_for_value(&self, value: f64, palette: &[[u8; 3]]) -> Option<[u8; 3]>;
+
+    /// Returns the interior boundaries between bins (the end of every bin except the last), without the counts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 5},
+    ///     Bin{bin_start: 1.0, bin_end: 2.5, count: 5},
+    ///     Bin{bin_start: 2.5, bin_end: 3.0, count: 5}
+    /// ];
+    ///
+    /// assert_eq!(class.breaks(), vec![1.0, 2.5]);
+    /// ```
+    fn breaks(&self) -> Vec<f64>;
+
+    /// Returns a Classification object given a set of breaks between bins and the dataset to count, just like breaks_to_classification
+    ///
+    /// For any Classification built from breaks on a dataset, `Classification::from_breaks(&class.breaks(), data)` reproduces it exactly. Classifications whose outer bins extend beyond the data (such as those from get_pretty_classification) are instead rebuilt with outer bins ending on the data's minimum and maximum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::{get_jenks_classification, ClassificationExt};
+    /// use classify::Classification;
+    ///
+    /// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+    /// let class: Classification = get_jenks_classification(3, &data);
+    ///
+    /// assert!(Classification::from_breaks(&class.breaks(), &data) == class);
+    /// ```
+    fn from_breaks<T: ToPrimitive>(breaks: &[f64], data: &[T]) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the indices of the bins that contain no data points
+    ///
+    /// Empty bins typically appear when the number of bins is large relative to the data or the data is clustered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::{get_equal_interval_classification, ClassificationExt};
+    /// use classify::Classification;
+    ///
+    /// let data: Vec<f64> = vec![1.0, 1.0, 2.0, 10.0];
+    /// let class: Classification = get_equal_interval_classification(3, &data);
+    ///
+    /// assert_eq!(class.empty_bins(), vec![1]);
+    /// ```
+    fn empty_bins(&self) -> Vec<usize>;
+
+    /// Merges every empty bin into its preceding bin (or, for empty bins at the start, into the first non-empty bin), keeping the remaining bins contiguous and their counts unchanged
+    ///
+    /// If every bin is empty, a single empty bin covering the whole range is kept
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::{get_equal_interval_classification, ClassificationExt};
+    /// use classify::{Classification, Bin};
+    ///
+    /// let data: Vec<f64> = vec![1.0, 1.0, 2.0, 10.0];
+    /// let mut class: Classification = get_equal_interval_classification(3, &data);
+    /// class.drop_empty_bins();
+    ///
+    /// assert_eq!(class, vec![
+    ///     Bin{bin_start: 1.0, bin_end: 7.0, count: 3},
+    ///     Bin{bin_start: 7.0, bin_end: 10.0, count: 1}
+    /// ]);
+    /// ```
+    fn drop_empty_bins(&mut self);
+
+    /// Returns the share of all counted data points that falls into each bin, as fractions that sum to 1
+    ///
+    /// If no data points were counted, every share is 0 rather than NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 3}
+    /// ];
+    ///
+    /// assert_eq!(class.percentages(), vec![0.25, 0.75]);
+    /// ```
+    fn percentages(&self) -> Vec<f64>;
+
+    /// Returns the running total of counts up to and including each bin
+    ///
+    /// The totals are u64 rather than usize to match Bin::count, so they cannot overflow on 32-bit targets such as wasm32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 3},
+    ///     Bin{bin_start: 2.0, bin_end: 3.0, count: 2}
+    /// ];
+    ///
+    /// assert_eq!(class.cumulative_counts(), vec![1, 4, 6]);
+    /// ```
+    fn cumulative_counts(&self) -> Vec<u64>;
+
+    /// Merges two neighboring bins into one spanning both of their ranges, with their counts summed
+    ///
+    /// The bins can be given in either order. Returns ClassifyError::NonAdjacentBins, leaving the Classification unchanged, if either index is out of range or the bins are not next to each other
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let mut class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 3},
+    ///     Bin{bin_start: 2.0, bin_end: 3.0, count: 2}
+    /// ];
+    /// class.merge_bins(1, 2).unwrap();
+    ///
+    /// assert_eq!(class, vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+    ///     Bin{bin_start: 1.0, bin_end: 3.0, count: 5}
+    /// ]);
+    /// assert!(class.merge_bins(0, 2).is_err());
+    /// ```
+    fn merge_bins(&mut self, i: usize, j: usize) -> Result<(), ClassifyError>;
+
+    /// Checks that every bin starts at or before its end and that each bin starts exactly where the previous one ends, as assumed by classify and by rendering code
+    ///
+    /// Returns ClassifyError::NonContiguousBins otherwise (including for NaN bounds). An empty Classification is valid. Classifications from get_unique_value_classification are made of single-value bins rather than contiguous ranges, so they do not pass this check
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::{ClassificationExt, ClassifyError};
+    /// use classify::{Classification, Bin};
+    ///
+    /// let mut class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 3}
+    /// ];
+    /// assert_eq!(class.validate(), Ok(()));
+    ///
+    /// class[1].bin_start = 0.5;
+    /// assert_eq!(class.validate(), Err(ClassifyError::NonContiguousBins));
+    /// ```
+    fn validate(&self) -> Result<(), ClassifyError>;
+
+    /// Rounds the breaks between bins to a granularity given by a SnapMode and re-counts the dataset against the rounded breaks
+    ///
+    /// Rounding can move a break past data points, so the counts are recomputed from the data rather than kept. As with from_breaks, the outer bins are rebuilt to end on the data's minimum and maximum
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A reference to the collection of unsorted data points the Classification was generated for
+    /// * `precision` - The granularity to round breaks to
+    ///
+    /// # Edge Cases
+    ///
+    /// * Breaks that round onto each other are merged, and breaks that round onto or beyond the data's minimum or maximum are dropped, so the number of bins can shrink
+    /// * NaN and infinite data points are ignored, and an empty Classification or a dataset without finite values leaves the Classification unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::{ClassificationExt, SnapMode};
+    /// use classify::{Classification, Bin};
+    ///
+    /// let data: Vec<f64> = vec![1.0, 3.0, 4.1, 6.0, 7.4, 10.0];
+    /// let mut class: Classification = Classification::from_breaks(&[4.0000001, 7.38294], &data);
+    /// class.snap_breaks(&data, SnapMode::Nice);
+    ///
+    /// assert_eq!(class.breaks(), vec![4.0, 8.0]);
+    /// assert_eq!(class.iter().map(|bin| bin.count).collect::<Vec<u64>>(), vec![2, 3, 1]);
+    /// ```
+    fn snap_breaks<T: ToPrimitive>(&mut self, data: &[T], precision: SnapMode);
+
+    /// Makes the outer bins unbounded by setting the first bin's start to negative infinity and the last bin's end to positive infinity, for legends such as "< 10" and "> 90"
+    ///
+    /// Counts are kept, and classify then places every non-NaN value in a bin. An empty Classification is left unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let mut class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 10.0, count: 5},
+    ///     Bin{bin_start: 10.0, bin_end: 90.0, count: 5},
+    ///     Bin{bin_start: 90.0, bin_end: 100.0, count: 5}
+    /// ];
+    /// class.with_open_ends();
+    ///
+    /// assert_eq!(class[0].bin_start, f64::NEG_INFINITY);
+    /// assert_eq!(class.classify(-1e300), Some(0));
+    /// assert_eq!(class.classify(250.0), Some(2));
+    /// ```
+    fn with_open_ends(&mut self);
+
+    /// Returns a copy of the Classification with the same bins but with counts recomputed against new data, so that datasets from different periods can be compared on the same breaks
+    ///
+    /// Each value is placed with classify_clamped, so values below the first bin's start are counted in the first bin and values above the last bin's end in the last bin. NaN values are not counted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 10.0, count: 2},
+    ///     Bin{bin_start: 10.0, bin_end: 20.0, count: 2}
+    /// ];
+    /// let next_year: Vec<f64> = vec![-3.0, 12.0, 15.0, 25.0];
+    ///
+    /// let result: Classification = class.recount(&next_year);
+    /// let expected: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 10.0, count: 1},
+    ///     Bin{bin_start: 10.0, bin_end: 20.0, count: 3}
+    /// ];
+    ///
+    /// assert!(result == expected);
+    /// ```
+    fn recount<T: ToPrimitive>(&self, data: &[T]) -> Self
+    where
+        Self: Sized;
+
+    /// Returns one legend label per bin, formatted following the given LabelOptions
+    ///
+    /// Bins are labeled "start - end", bins that start and end on the same value (as produced by get_unique_value_classification) with that single value, and open-ended bins as "< end" or "> start". A Classification with a single bin is only labeled as open-ended if one of its bounds is infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::{ClassificationExt, LabelOptions};
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1250.6, count: 5},
+    ///     Bin{bin_start: 1250.6, bin_end: 4000.0, count: 5},
+    ///     Bin{bin_start: 4000.0, bin_end: 10000.0, count: 5}
+    /// ];
+    ///
+    /// let labels = class.legend_labels(LabelOptions::new().precision(0).thousands_separator(',').prefix("$"));
+    /// assert_eq!(labels, vec!["$0 - $1,251", "$1,251 - $4,000", "$4,000 - $10,000"]);
+    ///
+    /// let labels = class.legend_labels(LabelOptions::new().precision(1).open_ends(true));
+    /// assert_eq!(labels, vec!["< 1250.6", "1250.6 - 4000.0", "> 4000.0"]);
+    /// ```
+    fn legend_labels(&self, opts: LabelOptions) -> Vec<String>;
+
+    /// Returns the index of the Bin within which each value falls, in the order of the data, for coloring individual points or joining classes back onto records
+    ///
+    /// Values are placed following the same rules as classify, except that values below the Classification's range are assigned to the first bin and values above it to the last bin. If `out_of_range` is given, the indices of those clamped values (and of NaN values, which are assigned to the first bin) within the data are appended to it
+    ///
+    /// If the Classification has no bins, no value can be assigned, so an empty vector is returned and `out_of_range` is left unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 2},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 2}
+    /// ];
+    /// let data: Vec<f64> = vec![1.5, 0.0, 2.0, -1.0, 0.5];
+    ///
+    /// let mut out_of_range: Vec<usize> = vec![];
+    /// assert_eq!(class.assign_all(&data, Some(&mut out_of_range)), vec![1, 0, 1, 0, 0]);
+    /// assert_eq!(out_of_range, vec![3]);
+    /// ```
+    fn assign_all<T: ToPrimitive>(
+        &self,
+        data: &[T],
+        out_of_range: Option<&mut Vec<usize>>,
+    ) -> Vec<usize>;
+
+    /// Returns a GitHub-flavored markdown table of the Classification with one row per bin, giving its index, range (labeled as by legend_labels with the default LabelOptions), count, and percentage of all counts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+    ///     Bin{bin_start: 1.0, bin_end: 2.5, count: 3}
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     class.to_markdown_table(),
+    ///     "| Class | Range | Count | Percent |\n\
+    ///      | ---: | --- | ---: | ---: |\n\
+    ///      | 0 | 0.00 - 1.00 | 1 | 25.0% |\n\
+    ///      | 1 | 1.00 - 2.50 | 3 | 75.0% |\n"
+    /// );
+    /// ```
+    fn to_markdown_table(&self) -> String;
+
+    /// Returns the same table as to_markdown_table as plain text, with its columns aligned by spaces for terminals
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+    ///     Bin{bin_start: 1.0, bin_end: 25.0, count: 30}
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     class.to_ascii_table(),
+    ///     "Class  Range         Count  Percent\n\
+    ///      -----  ------------  -----  -------\n    \
+    ///          0  0.00 - 1.00       1     3.2%\n    \
+    ///          1  1.00 - 25.00     30    96.8%\n"
+    /// );
+    /// ```
+    fn to_ascii_table(&self) -> String;
+
+    /// Returns a copy of the Classification with its bins in descending order, so that the first bin holds the highest range, for color ramps defined from high to low
+    ///
+    /// Each bin keeps its own start and end (with start below end), and the bin containing a value is unchanged: classify, classify_clamped, and assign_all return its index in the reversed order. Methods that rebuild or check bins (such as breaks, validate, merge_bins, and snap_breaks) expect ascending bins, so reverse the Classification back before using them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationExt;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let class: Classification = vec![
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 3}
+    /// ];
+    ///
+    /// let reversed: Classification = class.reversed();
+    /// let expected: Classification = vec![
+    ///     Bin{bin_start: 1.0, bin_end: 2.0, count: 3},
+    ///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1}
+    /// ];
+    ///
+    /// assert!(reversed == expected);
+    /// assert_eq!(reversed.classify(0.5), Some(1));
+    /// assert_eq!(reversed.classify(2.0), Some(0));
+    /// ```
+    fn reversed(&self) -> Self
+    where
+        Self: Sized;
+}
+
+impl ClassificationExt for Classification {
+    fn classify(&self, value: f64) -> Option<usize> {
+        let first = self.first()?;
+        let last = self.last()?;
+        if is_descending(self) {
+            if !(last.bin_start <= value && value <= first.bin_end) {
+                return None;
+            }
+            // Bins ending above the value come first, and the value falls into the last of them
+            let idx = self.partition_point(|bin| bin.bin_end > value);
+            return Some(idx.saturating_sub(1));
+        }
+        if !(first.bin_start <= value && value <= last.bin_end) {
+            return None;
+        }
+
+        let idx = self.partition_point(|bin| bin.bin_end <= value);
+        Some(core::cmp::min(idx, self.len() - 1))
+    }
+
+    fn classify_clamped(&self, value: f64) -> Option<usize> {
+        if self.is_empty() || value.is_nan() {
+            return None;
+        }
+        let (lowest, highest) = if is_descending(self) {
+            (self.len() - 1, 0)
+        } else {
+            (0, self.len() - 1)
+        };
+        if value < self[lowest].bin_start {
+            return Some(lowest);
+        }
+        if value > self[highest].bin_end {
+            return Some(highest);
+        }
+        self.classify(value)
+    }
+
+    #[cfg(feature = "colors")]
+    fn color_for_value(&self, value: f64, palette: &[[u8; 3]]) -> Option<[u8; 3]> {
+        let idx = self.classify(value)?;
+        assign_colors(self, palette).get(idx).copied()
+    }
+
+    fn breaks(&self) -> Vec<f64> {
+        let mut breaks: Vec<f64> = vec![];
+        for bin in self.iter().take(self.len().saturating_sub(1)) {
+            breaks.push(bin.bin_end);
+        }
+        breaks
+    }
+
+    fn from_breaks<T: ToPrimitive>(breaks: &[f64], data: &[T]) -> Self {
+        breaks_to_classification(breaks, data)
+    }
+
+    fn empty_bins(&self) -> Vec<usize> {
+        let mut empty: Vec<usize> = vec![];
+        for (i, bin) in self.iter().enumerate() {
+            if bin.count == 0 {
+                empty.push(i);
+            }
+        }
+        empty
+    }
+
+    fn drop_empty_bins(&mut self) {
+        if self.iter().all(|bin| bin.count == 0) {
+            if self.len() > 1 {
+                self[0].bin_end = self[self.len() - 1].bin_end;
+                self.truncate(1);
+            }
+            return;
+        }
+
+        let mut merged: Classification = Vec::with_capacity(self.len());
+        let mut leading_start: Option<f64> = None;
+        for mut bin in self.drain(..) {
+            if bin.count > 0 {
+                if let Some(start) = leading_start.take() {
+                    bin.bin_start = start;
+                }
+                merged.push(bin);
+            } else if let Some(prev) = merged.last_mut() {
+                prev.bin_end = bin.bin_end;
+            } else if leading_start.is_none() {
+                leading_start = Some(bin.bin_start);
+            }
+        }
+        *self = merged;
+    }
+
+    fn percentages(&self) -> Vec<f64> {
+        let total: u64 = self.iter().map(|bin| bin.count).sum();
+        if total == 0 {
+            return vec![0.0; self.len()];
+        }
+        self.iter()
+            .map(|bin| bin.count as f64 / total as f64)
+            .collect()
+    }
+
+    fn cumulative_counts(&self) -> Vec<u64> {
+        let mut total: u64 = 0;
+        let mut cumulative: Vec<u64> = vec![];
+        for bin in self {
+            total += bin.count;
+            cumulative.push(total);
+        }
+        cumulative
+    }
+
+    fn merge_bins(&mut self, i: usize, j: usize) -> Result<(), ClassifyError> {
+        let lower = core::cmp::min(i, j);
+        let upper = core::cmp::max(i, j);
+        if upper != lower + 1 || upper >= self.len() {
+            return Err(ClassifyError::NonAdjacentBins);
+        }
+
+        let removed = self.remove(upper);
+        self[lower].bin_end = removed.bin_end;
+        self[lower].count += removed.count;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ClassifyError> {
+        for (i, bin) in self.iter().enumerate() {
+            if bin.bin_start.is_nan() || bin.bin_end.is_nan() || bin.bin_start > bin.bin_end {
+                return Err(ClassifyError::NonContiguousBins);
+            }
+            if i > 0 && self[i - 1].bin_end != bin.bin_start {
+                return Err(ClassifyError::NonContiguousBins);
+            }
+        }
+        Ok(())
+    }
+    fn snap_breaks<T: ToPrimitive>(&mut self, data: &[T], precision: SnapMode) {
+        let data: Vec<f64> = to_vec_f64(data)
+            .into_iter()
+            .filter(|val| val.is_finite())
+            .collect();
+        if self.is_empty() || data.is_empty() {
+            return;
+        }
+        let min_value = data.iter().copied().fold(f64::INFINITY, f64::min);
+        let max_value = data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        let (nice_fraction, exp) = match precision {
+            SnapMode::Decimals(decimals) => (1.0, -decimals),
+            SnapMode::Nice => {
+                let mut bounds: Vec<f64> = vec![min_value];
+                bounds.extend(self.breaks());
+                bounds.push(max_value);
+                let narrowest = bounds
+                    .windows(2)
+                    .map(|pair| pair[1] - pair[0])
+                    .filter(|width| *width > 0.0)
+                    .fold(f64::INFINITY, f64::min);
+                nice_step_below(narrowest)
+            }
+        };
+
+        let mut snapped: Vec<f64> = vec![];
+        for brk in self.breaks() {
+            let rounded = round_to_step(brk, nice_fraction, exp);
+            let is_new = snapped.last().map_or(true, |last| rounded > *last);
+            if rounded > min_value && rounded < max_value && is_new {
+                snapped.push(rounded);
+            }
+        }
+        *self = breaks_to_classification(&snapped, &data);
+    }
+
+    fn with_open_ends(&mut self) {
+        if let Some(first) = self.first_mut() {
+            first.bin_start = f64::NEG_INFINITY;
+        }
+        if let Some(last) = self.last_mut() {
+            last.bin_end = f64::INFINITY;
+        }
+    }
+
+    fn recount<T: ToPrimitive>(&self, data: &[T]) -> Self {
+        let mut results: Classification = self.clone();
+        for bin in results.iter_mut() {
+            bin.count = 0;
+        }
+        for val in to_vec_f64(data) {
+            if let Some(idx) = self.classify_clamped(val) {
+                results[idx].count += 1;
+            }
+        }
+        results
+    }
+
+    fn legend_labels(&self, opts: LabelOptions) -> Vec<String> {
+        let last_idx = self.len().saturating_sub(1);
+        let mut labels: Vec<String> = vec![];
+        for (i, bin) in self.iter().enumerate() {
+            let open_start =
+                bin.bin_start == f64::NEG_INFINITY || (opts.open_ends && i == 0 && last_idx > 0);
+            let open_end =
+                bin.bin_end == f64::INFINITY || (opts.open_ends && i == last_idx && last_idx > 0);
+
+            let label = if open_start && !open_end {
+                format!("< {}", opts.format_bound(bin.bin_end))
+            } else if open_end && !open_start {
+                format!("> {}", opts.format_bound(bin.bin_start))
+            } else if bin.bin_start == bin.bin_end {
+                opts.format_bound(bin.bin_start)
+            } else {
+                format!(
+                    "{} - {}",
+                    opts.format_bound(bin.bin_start),
+                    opts.format_bound(bin.bin_end)
+                )
+            };
+            labels.push(label);
+        }
+        labels
+    }
+
+    fn assign_all<T: ToPrimitive>(
+        &self,
+        data: &[T],
+        mut out_of_range: Option<&mut Vec<usize>>,
+    ) -> Vec<usize> {
+        if self.is_empty() {
+            return vec![];
+        }
+
+        let mut assignments: Vec<usize> = vec![];
+        for (i, val) in to_vec_f64(data).into_iter().enumerate() {
+            let idx = match self.classify(val) {
+                Some(idx) => idx,
+                None => {
+                    if let Some(out_of_range) = out_of_range.as_mut() {
+                        out_of_range.push(i);
+                    }
+                    self.classify_clamped(val).unwrap_or(0)
+                }
+            };
+            assignments.push(idx);
+        }
+        assignments
+    }
+
+    fn to_markdown_table(&self) -> String {
+        let mut table = format!(
+            "| {} |\n| ---: | --- | ---: | ---: |\n",
+            TABLE_HEADERS.join(" | ")
+        );
+        for row in table_rows(self) {
+            table.push_str(&format!("| {} |\n", row.join(" | ")));
+        }
+        table
+    }
+
+    fn to_ascii_table(&self) -> String {
+        let rows = table_rows(self);
+        let mut widths: [usize; 4] = [0; 4];
+        for (col, header) in TABLE_HEADERS.iter().enumerate() {
+            widths[col] = rows
+                .iter()
+                .map(|row| row[col].chars().count())
+                .fold(header.len(), usize::max);
+        }
+
+        // The range column is aligned to the left and the numeric columns to the right
+        let format_row = |cells: [&str; 4]| -> String {
+            format!(
+                "{:>w0$}  {:<w1$}  {:>w2$}  {:>w3$}\n",
+                cells[0],
+                cells[1],
+                cells[2],
+                cells[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3]
+            )
+        };
+
+        let mut table = format_row(TABLE_HEADERS);
+        let dashes: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        table.push_str(&format_row([
+            &dashes[0], &dashes[1], &dashes[2], &dashes[3],
+        ]));
+        for row in rows.iter() {
+            table.push_str(&format_row([&row[0], &row[1], &row[2], &row[3]]));
+        }
+        table
+    }
+
+    fn reversed(&self) -> Self {
+        self.iter().rev().cloned().collect()
+    }
+}
+
+/// Returns whether a Classification's bins are in descending order, as produced by reversed
+fn is_descending(classification: &Classification) -> bool {
+    match (classification.first(), classification.last()) {
+        (Some(first), Some(last)) => first.bin_start > last.bin_start,
+        _ => false,
+    }
+}
+
+/// The headers of the columns of to_markdown_table and to_ascii_table
+const TABLE_HEADERS: [&str; 4] = ["Class", "Range", "Count", "Percent"];
+
+/// Returns the cells of each row of to_markdown_table and to_ascii_table
+fn table_rows(classification: &Classification) -> Vec<[String; 4]> {
+    let labels = classification.legend_labels(LabelOptions::new());
+    let percentages = classification.percentages();
+    classification
+        .iter()
+        .zip(labels)
+        .zip(percentages)
+        .enumerate()
+        .map(|(i, ((bin, label), percentage))| {
+            [
+                i.to_string(),
+                label,
+                bin.count.to_string(),
+                format!("{:.1}%", percentage * 100.0),
+            ]
+        })
+        .collect()
+}
+
+/// Returns the largest step of the form 1, 2, or 5 times a power of 10 that is smaller than a positive width (1 for widths that are not finite), as the pair of that factor and exponent
+fn nice_step_below(width: f64) -> (f64, i32) {
+    if !width.is_finite() || width <= 0.0 {
+        return (1.0, 0);
+    }
+    let mut exp = width.log10().floor() as i32;
+    loop {
+        for nice_fraction in [5.0, 2.0, 1.0] {
+            if nice_fraction * 10_f64.powi(exp) < width {
+                return (nice_fraction, exp);
+            }
+        }
+        exp -= 1;
+    }
+}
+
+/// Rounds a value to the nearest multiple of nice_fraction * 10^exp, dividing by an exact power of 10 for negative exponents so results such as 0.3 come out as the closest f64
+fn round_to_step(val: f64, nice_fraction: f64, exp: i32) -> f64 {
+    if exp >= 0 {
+        let step = nice_fraction * 10_f64.powi(exp);
+        (val / step).round() * step
+    } else {
+        let scale = 10_f64.powi(-exp);
+        (val * scale / nice_fraction).round() * nice_fraction / scale
+    }
+}
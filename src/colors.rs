@@ -0,0 +1,64 @@
+use alloc::{vec, vec::Vec};
+
+use crate::utilities::Classification;
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+
+/// Assigns an RGB color from a sequential palette to each bin of a Classification
+///
+/// Bin `i` receives `palette[i]` when the palette has at least as many colors as there are bins. Otherwise, the bins are spread evenly across the palette (the first bin taking its first color and the last bin its last) and colors between palette entries are linearly interpolated per channel
+///
+/// # Arguments
+///
+/// * `c` - A reference to a Classification generated through any method
+/// * `palette` - A reference to a collection of RGB colors ordered from the lowest to the highest bin
+///
+/// # Edge Cases
+///
+/// * If the palette is empty, an empty vector is returned
+/// * A single-color palette assigns that color to every bin
+/// * Palette entries beyond the number of bins are ignored
+///
+/// # Examples
+///
+/// ```
+/// use classify::assign_colors;
+/// use classify::{Classification, Bin};
+///
+/// let class: Classification = vec![
+///     Bin{bin_start: 0.0, bin_end: 1.0, count: 5},
+///     Bin{bin_start: 1.0, bin_end: 2.0, count: 5},
+///     Bin{bin_start: 2.0, bin_end: 3.0, count: 5}
+/// ];
+/// let palette: Vec<[u8; 3]> = vec![[255, 255, 255], [0, 0, 100]];
+///
+/// assert_eq!(assign_colors(&class, &palette), vec![[255, 255, 255], [128, 128, 178], [0, 0, 100]]);
+/// ```
+pub fn assign_colors(c: &Classification, palette: &[[u8; 3]]) -> Vec<[u8; 3]> {
+    if palette.is_empty() {
+        return vec![];
+    }
+    let num_bins = c.len();
+    if palette.len() >= num_bins || palette.len() == 1 {
+        return (0..num_bins)
+            .map(|i| palette[core::cmp::min(i, palette.len() - 1)])
+            .collect();
+    }
+
+    let scale = (palette.len() - 1) as f64 / (num_bins - 1) as f64;
+    (0..num_bins)
+        .map(|i| {
+            let pos = i as f64 * scale;
+            let lower = core::cmp::min(pos.floor() as usize, palette.len() - 2);
+            let frac = pos - lower as f64;
+            let mut color = [0; 3];
+            for (channel, val) in color.iter_mut().enumerate() {
+                let start = palette[lower][channel] as f64;
+                let end = palette[lower + 1][channel] as f64;
+                *val = (start + (end - start) * frac).round() as u8;
+            }
+            color
+        })
+        .collect()
+}
@@ -0,0 +1,117 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64, validate_data, MAX_INTERVAL_BINS};
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+use num_traits::ToPrimitive;
+
+/// Returns a Classification object following the Defined Interval Breaks algorithm given the desired width of each bin and one-dimensional data
+///
+/// # Arguments
+///
+/// * `interval` - The width of each bin, starting from the data's minimum
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns `ClassifyError::NonPositiveInterval` if the interval is less than or equal to zero
+/// * Returns `ClassifyError::EmptyData` if data contains no values
+/// * Returns `ClassifyError::NonFinite` if data contains NaN or infinite values
+/// * Returns `ClassifyError::TooManyBins` if the interval would split the data's range into more than 1,000,000 bins
+/// * If the data's range is not an exact multiple of the interval, the last bin is narrower than the others and still includes the maximum
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_defined_interval_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<u32> = vec![0, 10, 30, 40, 55, 60];
+///
+/// let result: Classification = get_defined_interval_classification(25.0, &data).unwrap();
+/// let expected: Classification = vec![
+///     Bin{bin_start: 0.0, bin_end: 25.0, count: 2},
+///     Bin{bin_start: 25.0, bin_end: 50.0, count: 2},
+///     Bin{bin_start: 50.0, bin_end: 60.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_defined_interval_classification<T: ToPrimitive, S: ToPrimitive>(
+    interval: S,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    let breaks: Vec<f64> = get_defined_interval_breaks(interval, data)?;
+    Ok(breaks_to_classification(&breaks, data))
+}
+
+/// Returns a vector of breaks spaced a fixed interval apart (starting at the data's minimum) given the desired width of each bin and a dataset
+///
+/// # Arguments
+///
+/// * `interval` - The width of each bin, starting from the data's minimum
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns `ClassifyError::NonPositiveInterval` if the interval is less than or equal to zero
+/// * Returns `ClassifyError::EmptyData` if data contains no values
+/// * Returns `ClassifyError::NonFinite` if data contains NaN or infinite values
+/// * Returns `ClassifyError::TooManyBins` if the interval would split the data's range into more than 1,000,000 bins
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_defined_interval_breaks;
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![0.0, 10.0, 30.0, 40.0, 55.0, 60.0];
+///
+/// assert_eq!(get_defined_interval_breaks(25.0, &data), Ok(vec![25.0, 50.0]));
+/// assert_eq!(get_defined_interval_breaks(0.0, &data), Err(ClassifyError::NonPositiveInterval));
+/// ```
+pub fn get_defined_interval_breaks<T: ToPrimitive, S: ToPrimitive>(
+    interval: S,
+    data: &[T],
+) -> Result<Vec<f64>, ClassifyError> {
+    let interval = interval.to_f64().unwrap();
+    // Written so that a NaN interval is rejected as well
+    if interval.is_nan() || interval <= 0.0 {
+        return Err(ClassifyError::NonPositiveInterval);
+    }
+
+    validate_data(data)?;
+    let data = to_vec_f64(data);
+
+    let mut min_value = data[0];
+    let mut max_value = data[0];
+    for item in data {
+        if item < min_value {
+            min_value = item;
+        } else if item > max_value {
+            max_value = item;
+        }
+    }
+
+    // The range of finite data can still overflow, and a tiny interval would place breaks without end
+    let num_bins = ((max_value - min_value) / interval).ceil();
+    if !num_bins.is_finite() || num_bins > MAX_INTERVAL_BINS as f64 {
+        return Err(ClassifyError::TooManyBins);
+    }
+
+    let mut result: Vec<f64> = vec![];
+    for i in 1..=(num_bins as usize) {
+        let new_break = min_value + i as f64 * interval;
+        // Rounding in the bin count can allow one break too many, which would fall on or past the maximum
+        if new_break < max_value {
+            result.push(new_break);
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,232 @@
+use alloc::{vec, vec::Vec};
+
+use crate::classification::ClassificationExt;
+use crate::error::ClassifyError;
+use crate::percentile::{calc_percentile, PercentileInterpolation};
+use crate::utilities::to_vec_f64;
+use crate::utilities::{validate_data, validate_num_bins};
+use crate::utilities::{Bin, Classification};
+use num_traits::ToPrimitive;
+
+/// Represents how the bins on each side of the center of a diverging classification are sized
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DivergingSpacing {
+    /// Every bin has the same width, with the bins on both sides together spanning the largest distance of any data point from the center
+    EqualWidth,
+    /// The bins on each side hold equal numbers of the data points on that side (as Quantile Breaks would), ending on the data's minimum and maximum
+    EqualCount,
+}
+
+/// Returns a Classification object with the same number of bins below and above a neutral center given one-dimensional data, for maps of changes or differences (e.g. temperature anomalies)
+///
+/// The center is always a break between bins. Data points equal to the center fall into the first bin above it
+///
+/// # Arguments
+///
+/// * `num_bins_per_side` - An integer (usize) representing the desired number of bins on each side of the center
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `center` - The neutral value to classify the data around
+/// * `spacing` - Whether the bins on each side have equal widths or hold equal numbers of data points
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * A num_bins_per_side of 0 is treated as 1
+/// * With DivergingSpacing::EqualWidth, the outer bins are mirrored around the center, so the side with the smaller spread of data ends beyond the data and may contain empty bins
+/// * With DivergingSpacing::EqualCount, if no data points lie on one side of the center, the bins on that side are empty and zero-width on the center
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_diverging_classification, DivergingSpacing};
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f64> = vec![-3.0, -1.0, 0.5, 1.0, 2.0, 4.0];
+///
+/// let result: Classification = get_diverging_classification(2, &data, 0.0, DivergingSpacing::EqualWidth);
+/// let expected: Classification = vec![
+///     Bin{bin_start: -4.0, bin_end: -2.0, count: 1},
+///     Bin{bin_start: -2.0, bin_end: 0.0, count: 1},
+///     Bin{bin_start: 0.0, bin_end: 2.0, count: 2},
+///     Bin{bin_start: 2.0, bin_end: 4.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_diverging_classification<T: ToPrimitive>(
+    num_bins_per_side: usize,
+    data: &[T],
+    center: f64,
+    spacing: DivergingSpacing,
+) -> Classification {
+    let data = to_vec_f64(data);
+    let breaks = get_diverging_breaks(num_bins_per_side, &data, center, spacing);
+
+    let (lower, upper) = match spacing {
+        DivergingSpacing::EqualWidth => {
+            let spread = max_distance(&data, center);
+            (center - spread, center + spread)
+        }
+        DivergingSpacing::EqualCount => {
+            let mut lower = center;
+            let mut upper = center;
+            for val in &data {
+                lower = lower.min(*val);
+                upper = upper.max(*val);
+            }
+            (lower, upper)
+        }
+    };
+
+    let mut bounds: Vec<f64> = vec![lower];
+    bounds.extend(breaks);
+    bounds.push(upper);
+
+    let mut results: Classification = vec![];
+    for pair in bounds.windows(2) {
+        results.push(Bin {
+            bin_start: pair[0],
+            bin_end: pair[1],
+            count: 0,
+        });
+    }
+    for val in &data {
+        if let Some(idx) = results.classify(*val) {
+            results[idx].count += 1;
+        }
+    }
+
+    results
+}
+
+/// Returns a diverging Classification object around a neutral center, or an error instead of a panic if the input cannot be classified
+///
+/// # Arguments
+///
+/// * `num_bins_per_side` - An integer (usize) representing the desired number of bins on each side of the center
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `center` - The neutral value to classify the data around
+/// * `spacing` - Whether the bins on each side have equal widths or hold equal numbers of data points
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins_per_side is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values or the center is not finite
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_diverging_classification, try_get_diverging_classification, DivergingSpacing};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![-3.0, -1.0, 0.5, 1.0, 2.0, 4.0];
+/// let spacing = DivergingSpacing::EqualCount;
+/// assert!(try_get_diverging_classification(2, &data, 0.0, spacing).unwrap() == get_diverging_classification(2, &data, 0.0, spacing));
+///
+/// assert_eq!(try_get_diverging_classification(0, &data, 0.0, spacing), Err(ClassifyError::ZeroBins));
+/// ```
+pub fn try_get_diverging_classification<T: ToPrimitive>(
+    num_bins_per_side: usize,
+    data: &[T],
+    center: f64,
+    spacing: DivergingSpacing,
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins_per_side)?;
+    validate_data(data)?;
+    if !center.is_finite() {
+        return Err(ClassifyError::NonFinite);
+    }
+    Ok(get_diverging_classification(
+        num_bins_per_side,
+        data,
+        center,
+        spacing,
+    ))
+}
+
+/// Returns a vector of breaks with the same number of bins below and above a neutral center given a dataset, including the center itself
+///
+/// # Arguments
+///
+/// * `num_bins_per_side` - The desired number of bins on each side of the center
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+/// * `center` - The neutral value to generate breaks around
+/// * `spacing` - Whether the bins on each side have equal widths or hold equal numbers of data points
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * A num_bins_per_side of 0 is treated as 1
+/// * The outer bounds are not part of the breaks, so breaks_to_classification on these breaks ends the outer bins on the data's minimum and maximum rather than mirroring them as get_diverging_classification does
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_diverging_breaks, DivergingSpacing};
+///
+/// let data: Vec<f64> = vec![-3.0, -1.0, 0.5, 1.0, 2.0, 4.0];
+///
+/// let result: Vec<f64> = get_diverging_breaks(2, &data, 0.0, DivergingSpacing::EqualCount);
+///
+/// assert_eq!(result, vec![-2.0, 0.0, 1.5]);
+/// ```
+pub fn get_diverging_breaks<T: ToPrimitive>(
+    num_bins_per_side: usize,
+    data: &[T],
+    center: f64,
+    spacing: DivergingSpacing,
+) -> Vec<f64> {
+    let data = to_vec_f64(data);
+    let num_bins_per_side = core::cmp::max(num_bins_per_side, 1);
+
+    let mut breaks: Vec<f64> = vec![];
+    match spacing {
+        DivergingSpacing::EqualWidth => {
+            let width = max_distance(&data, center) / num_bins_per_side as f64;
+            for i in (1..num_bins_per_side).rev() {
+                breaks.push(center - width * i as f64);
+            }
+            breaks.push(center);
+            for i in 1..num_bins_per_side {
+                breaks.push(center + width * i as f64);
+            }
+        }
+        DivergingSpacing::EqualCount => {
+            let mut below: Vec<f64> = data.iter().copied().filter(|val| *val < center).collect();
+            let mut above: Vec<f64> = data.iter().copied().filter(|val| *val >= center).collect();
+            below.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            above.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            breaks.extend(side_quantiles(num_bins_per_side, &below, center));
+            breaks.push(center);
+            breaks.extend(side_quantiles(num_bins_per_side, &above, center));
+        }
+    }
+
+    breaks
+}
+
+/// Returns the breaks splitting the (sorted) data points on one side of the center into bins of equal counts, all on the center if that side has no data points
+fn side_quantiles(num_bins: usize, side: &[f64], center: f64) -> Vec<f64> {
+    let mut breaks: Vec<f64> = vec![];
+    for i in 1..num_bins {
+        if side.is_empty() {
+            breaks.push(center);
+        } else {
+            let perc = 100.0 * i as f64 / num_bins as f64;
+            breaks.push(calc_percentile(perc, side, PercentileInterpolation::Linear));
+        }
+    }
+    breaks
+}
+
+/// Returns the largest distance of any data point from the center
+fn max_distance(data: &[f64], center: f64) -> f64 {
+    let mut spread: f64 = 0.0;
+    for val in data {
+        spread = spread.max((val - center).abs());
+    }
+    spread
+}
@@ -1,5 +1,9 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
 use crate::utilities::Classification;
 use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
 use num_traits::ToPrimitive;
 
 /// Returns a Classification object following the Equal Interval Breaks algorithm given the desired number of bins and one-dimensional data
@@ -13,6 +17,7 @@ use num_traits::ToPrimitive;
 ///
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
 /// * If there is a wide enoguh gap in the data, this algorithm may produce one or more empty bins
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
 ///
 /// # Examples
 ///
@@ -40,6 +45,41 @@ pub fn get_equal_interval_classification<T: ToPrimitive>(
     breaks_to_classification(&breaks, data)
 }
 
+/// Returns a Classification object following the Equal Interval algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_equal_interval_classification, try_get_equal_interval_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_equal_interval_classification(3, &data).unwrap() == get_equal_interval_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_equal_interval_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_equal_interval_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_equal_interval_classification(num_bins, data))
+}
+
 /// Returns a vector of breaks generated through the Equal Interval Breaks algorithm given the desired number of bins and a dataset
 ///
 /// # Arguments
@@ -51,6 +91,7 @@ pub fn get_equal_interval_classification<T: ToPrimitive>(
 ///
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
 /// * If there is a wide enoguh gap in the data, this algorithm may produce one or more empty bins
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
 ///
 /// # Examples
 ///
@@ -78,6 +119,9 @@ pub fn get_equal_interval_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) ->
     }
 
     let mut result: Vec<f64> = vec![];
+    if min_value == max_value {
+        return result;
+    }
     for i in 1..num_bins {
         result.push(min_value + (max_value - min_value) * (i as f64 / num_bins as f64));
     }
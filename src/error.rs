@@ -0,0 +1,87 @@
+use core::fmt;
+
+/// Represents the ways in which generating a Classification can fail
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassifyError {
+    /// The supplied breaks are not strictly increasing
+    UnsortedBreaks,
+    /// A percentile lies outside of the range 0 to 100
+    PercentileOutOfRange,
+    /// The data contains zero or negative values, which have no logarithm
+    NonPositiveForLog,
+    /// The data contains zero or negative values, which harmonic intervals cannot span
+    NonPositiveForHarmonic,
+    /// The data contains negative values, which have no square root
+    NegativeForSqrt,
+    /// A bin width is zero, negative, or not a number
+    NonPositiveInterval,
+    /// The dataset contains no values
+    EmptyData,
+    /// The requested number of bins is zero
+    ZeroBins,
+    /// The data contains NaN or infinite values
+    NonFinite,
+    /// The bins to merge are out of range or not next to each other
+    NonAdjacentBins,
+    /// A bin ends before it starts, or consecutive bins do not meet
+    NonContiguousBins,
+    /// There is not exactly one weight per value, or a weight is negative or not finite
+    InvalidWeights,
+    /// Two collections that must hold one value per observation have different lengths
+    MismatchedLengths,
+    /// A textual list of breaks contains a token that is not a finite number
+    MalformedBreaks,
+    /// A bin width is so small relative to the data's range that it would produce too many bins
+    TooManyBins,
+}
+
+impl fmt::Display for ClassifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClassifyError::UnsortedBreaks => write!(f, "breaks must be strictly increasing"),
+            ClassifyError::PercentileOutOfRange => {
+                write!(f, "percentiles must lie between 0 and 100")
+            }
+            ClassifyError::NonPositiveForLog => {
+                write!(f, "logarithmic breaks require strictly positive data")
+            }
+            ClassifyError::NonPositiveForHarmonic => {
+                write!(f, "harmonic breaks require strictly positive data")
+            }
+            ClassifyError::NegativeForSqrt => {
+                write!(f, "square root breaks require non-negative data")
+            }
+            ClassifyError::NonPositiveInterval => write!(f, "intervals must be greater than zero"),
+            ClassifyError::EmptyData => write!(f, "data must contain at least one value"),
+            ClassifyError::ZeroBins => write!(f, "the number of bins must be at least 1"),
+            ClassifyError::NonFinite => write!(f, "data must not contain NaN or infinite values"),
+            ClassifyError::NonAdjacentBins => {
+                write!(f, "only two existing, neighboring bins can be merged")
+            }
+            ClassifyError::NonContiguousBins => {
+                write!(
+                    f,
+                    "bins must be ordered and each must start where the previous one ends"
+                )
+            }
+            ClassifyError::InvalidWeights => {
+                write!(
+                    f,
+                    "weights must be finite, non-negative, and given one per value"
+                )
+            }
+            ClassifyError::MismatchedLengths => {
+                write!(f, "inputs must hold the same number of observations")
+            }
+            ClassifyError::MalformedBreaks => {
+                write!(f, "breaks must be finite numbers separated by commas")
+            }
+            ClassifyError::TooManyBins => {
+                write!(f, "the interval is too small for the data's range")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ClassifyError {}
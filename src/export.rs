@@ -0,0 +1,93 @@
+#[cfg(feature = "serde")]
+use crate::classification::ClassificationExt;
+use crate::utilities::Classification;
+use std::io::Write;
+
+/// Converts a Classification into a JSON value suitable for GeoJSON feature properties or a map legend
+///
+/// The result is an object holding the overall `min` and `max` of the Classification, its `breaks`, and a `bins` array with one `{ "min": .., "max": .., "count": .. }` object per bin
+///
+/// # Arguments
+///
+/// * `classification` - A reference to a Classification generated through any method
+///
+/// # Edge Cases
+///
+/// * NaN or infinite bounds are written as null, since JSON cannot represent them
+/// * An empty Classification produces null `min` and `max` values and empty `breaks` and `bins` arrays
+///
+/// # Examples
+///
+/// ```
+/// use classify::classification_to_json;
+/// use classify::{Classification, Bin};
+/// use serde_json::json;
+///
+/// let classification: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 8.0, count: 3}
+/// ];
+///
+/// assert_eq!(classification_to_json(&classification), json!({
+///     "min": 1.0,
+///     "max": 8.0,
+///     "breaks": [4.0],
+///     "bins": [
+///         {"min": 1.0, "max": 4.0, "count": 2},
+///         {"min": 4.0, "max": 8.0, "count": 3}
+///     ]
+/// }));
+/// ```
+#[cfg(feature = "serde")]
+pub fn classification_to_json(classification: &Classification) -> serde_json::Value {
+    use serde_json::json;
+
+    let bins: Vec<serde_json::Value> = classification
+        .iter()
+        .map(|bin| json!({"min": bin.bin_start, "max": bin.bin_end, "count": bin.count}))
+        .collect();
+
+    json!({
+        "min": classification.first().map(|bin| bin.bin_start),
+        "max": classification.last().map(|bin| bin.bin_end),
+        "breaks": classification.breaks(),
+        "bins": bins,
+    })
+}
+
+/// Writes a Classification as CSV with a `bin_start,bin_end,count` header followed by one row per bin
+///
+/// Bounds are written with Rust's shortest representation that parses back to the same f64, so no precision is lost
+///
+/// # Arguments
+///
+/// * `w` - A writer to output the CSV to (e.g. a file, stdout, or a `Vec<u8>`)
+/// * `c` - A reference to a Classification generated through any method
+///
+/// # Edge Cases
+///
+/// * NaN and infinite bounds are written as `NaN`, `inf`, and `-inf`
+/// * An empty Classification writes only the header
+///
+/// # Examples
+///
+/// ```
+/// use classify::write_classification_csv;
+/// use classify::{Classification, Bin};
+///
+/// let classification: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.5, count: 2},
+///     Bin{bin_start: 4.5, bin_end: 8.0, count: 3}
+/// ];
+/// let mut csv: Vec<u8> = vec![];
+/// write_classification_csv(&mut csv, &classification).unwrap();
+///
+/// assert_eq!(String::from_utf8(csv).unwrap(), "bin_start,bin_end,count\n1,4.5,2\n4.5,8,3\n");
+/// ```
+pub fn write_classification_csv<W: Write>(w: &mut W, c: &Classification) -> std::io::Result<()> {
+    writeln!(w, "bin_start,bin_end,count")?;
+    for bin in c {
+        writeln!(w, "{},{},{}", bin.bin_start, bin.bin_end, bin.count)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,334 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::ToPrimitive;
+
+use crate::error::ClassifyError;
+use crate::jenks::calc_gssd;
+use crate::utilities::{breaks_to_classification, create_unique_val_mapping, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+use crate::utilities::{Classification, UniqueVal, UniqueValSums};
+
+/// Returns a Classification object following the Fisher-Jenks algorithm given the desired number of bins and one-dimensional data
+///
+/// Unlike get_jenks_classification, which samples random breaks, this finds the breaks that minimize the within-class sum of squared deviations exactly through dynamic programming
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Run time grows with the square of the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_fisher_jenks_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<usize> = vec![1, 2, 4, 5, 7, 8];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_fisher_jenks_classification(num_bins, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 7.0, count: 2},
+///     Bin{bin_start: 7.0, bin_end: 8.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_fisher_jenks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Classification {
+    let breaks: Vec<f64> = get_fisher_jenks_breaks(num_bins, data);
+    breaks_to_classification(&breaks, data)
+}
+
+/// Returns a Classification object following the Fisher-Jenks algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_fisher_jenks_classification, try_get_fisher_jenks_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_fisher_jenks_classification(3, &data).unwrap() == get_fisher_jenks_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_fisher_jenks_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_fisher_jenks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_fisher_jenks_classification(num_bins, data))
+}
+
+/// Returns a vector of breaks generated through the Fisher-Jenks algorithm given the desired number of bins and a dataset
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Run time grows with the square of the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_fisher_jenks_breaks;
+///
+/// let data: Vec<i8> = vec![1, 2, 4, 5, 7, 8];
+/// let num_bins = 3;
+///
+/// let result: Vec<f64> = get_fisher_jenks_breaks(num_bins, &data);
+///
+/// assert_eq!(result, vec![4.0, 7.0]);
+/// ```
+pub fn get_fisher_jenks_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    let num_unique_vals = unique_val_map.len();
+    let true_num_bins = core::cmp::min(num_unique_vals, num_bins);
+    if true_num_bins <= 1 {
+        return vec![];
+    }
+
+    let unique_breaks = fisher_jenks_unique_breaks(true_num_bins, &unique_val_map);
+
+    let mut nat_breaks: Vec<f64> = vec![];
+    for idx in unique_breaks {
+        nat_breaks.push(unique_val_map[idx].val);
+    }
+
+    nat_breaks
+}
+
+/// Finds the unique value indices at which each bin (after the first) starts such that the within-class sum of squared deviations is minimized
+///
+/// # Arguments
+///
+/// * `num_bins` - The number of bins (at least 2 and at most the number of unique values)
+/// * `u_val_map` - A reference to a map of unique values to their first and last occurrences in the dataset
+pub fn fisher_jenks_unique_breaks(num_bins: usize, u_val_map: &[UniqueVal]) -> Vec<usize> {
+    let sums = UniqueValSums::new(u_val_map);
+    optimal_unique_breaks(num_bins, u_val_map.len(), |start, end| sums.ssd(start, end))
+}
+
+/// Finds the unique value indices at which each bin (after the first) starts such that the sum of the costs of the bins is minimized, through dynamic programming
+///
+/// # Arguments
+///
+/// * `num_bins` - The number of bins (at least 2 and at most the number of unique values)
+/// * `num_unique_vals` - The number of unique values in the dataset
+/// * `cost` - A function returning the cost of a bin holding the unique values from a start index (inclusive) to an end index (exclusive)
+pub fn optimal_unique_breaks<C: Fn(usize, usize) -> f64>(
+    num_bins: usize,
+    num_unique_vals: usize,
+    cost: C,
+) -> Vec<usize> {
+    // costs[j] holds the minimal cost of splitting the first j + 1 unique values into the current number of bins
+    let mut costs: Vec<f64> = (0..num_unique_vals).map(|j| cost(0, j + 1)).collect();
+    let mut starts: Vec<Vec<usize>> = vec![vec![0; num_unique_vals]; num_bins];
+
+    for (bin, bin_starts) in starts.iter_mut().enumerate().skip(1) {
+        costs = next_costs(bin, &costs, &cost, bin_starts);
+    }
+
+    let mut breaks: Vec<usize> = vec![0; num_bins - 1];
+    let mut end = num_unique_vals - 1;
+    for bin in (1..num_bins).rev() {
+        let start = starts[bin][end];
+        breaks[bin - 1] = start;
+        end = start - 1;
+    }
+
+    breaks
+}
+
+/// Returns the minimal cost of splitting the first j + 1 unique values into bin + 1 bins for every j, given the minimal costs for bin bins, while recording the optimal start of the last bin in bin_starts
+fn next_costs<C: Fn(usize, usize) -> f64>(
+    bin: usize,
+    costs: &[f64],
+    cost: &C,
+    bin_starts: &mut [usize],
+) -> Vec<f64> {
+    let num_unique_vals = costs.len();
+    let mut new_costs: Vec<f64> = vec![f64::INFINITY; num_unique_vals];
+    for j in bin..num_unique_vals {
+        for start in bin..(j + 1) {
+            let total = costs[start - 1] + cost(start, j + 1);
+            if total < new_costs[j] {
+                new_costs[j] = total;
+                bin_starts[j] = start;
+            }
+        }
+    }
+    new_costs
+}
+
+/// Returns the smallest number of bins for which the optimal (Fisher-Jenks) breaks of a dataset reach a target goodness of variance fit (GVF)
+///
+/// The optimal within-class costs for each number of bins are built on those for one bin fewer, so increasing class counts are tried without recomputing from scratch and the search stops as soon as the target is met
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to choose a number of bins for
+/// * `gvf_target` - The GVF (between 0 and 1) that the breaks should reach
+/// * `max_bins` - The largest number of bins to consider
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The GVF is assumed to increase monotonically with the number of bins, which holds for optimal breaks
+/// * If the target is never reached, max_bins (capped at the number of unique values in the dataset) is returned
+/// * If all data points share the same value, 1 is returned
+///
+/// # Examples
+///
+/// ```
+/// use classify::suggest_num_bins;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+///
+/// assert_eq!(suggest_num_bins(&data, 0.9, 5), 3);
+/// assert_eq!(suggest_num_bins(&data, 1.0, 4), 4);
+/// ```
+pub fn suggest_num_bins<T: ToPrimitive>(data: &[T], gvf_target: f64, max_bins: usize) -> usize {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    let num_unique_vals = unique_val_map.len();
+    let max_bins = core::cmp::max(core::cmp::min(num_unique_vals, max_bins), 1);
+    if max_bins == 1 || gvf_target <= 0.0 {
+        return 1;
+    }
+
+    let gssd = calc_gssd(&sorted_data);
+    let sums = UniqueValSums::new(&unique_val_map);
+
+    let mut costs: Vec<f64> = (0..num_unique_vals).map(|j| sums.ssd(0, j + 1)).collect();
+    let mut bin_starts: Vec<usize> = vec![0; num_unique_vals];
+    for bin in 1..max_bins {
+        costs = next_costs(
+            bin,
+            &costs,
+            &|start, end| sums.ssd(start, end),
+            &mut bin_starts,
+        );
+        let gvf = 1.0 - costs[num_unique_vals - 1] / gssd;
+        if gvf >= gvf_target {
+            return bin + 1;
+        }
+    }
+
+    max_bins
+}
+
+/// Returns the number of bins at the elbow of the curve of goodness of variance fit (GVF) against the number of bins, along with that curve, using the optimal (Fisher-Jenks) breaks for each number of bins
+///
+/// Following the Kneedle algorithm, the numbers of bins and their GVFs are scaled to the range 0 to 1, and the elbow is the number of bins whose scaled GVF lies furthest above the line from one bin to max_bins. This is the point of diminishing returns, beyond which extra bins add little GVF, and unlike suggest_num_bins it does not need a target GVF
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to choose a number of bins for
+/// * `max_bins` - The largest number of bins to consider
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The curve holds the GVF for 1 (always 0), 2, and so on up to max_bins bins, with max_bins capped at the number of unique values in the dataset
+/// * If fewer than 3 numbers of bins can be considered, or no GVF lies above the line, 1 is returned
+///
+/// # Examples
+///
+/// ```
+/// use classify::suggest_num_bins_elbow;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 3.0, 11.0, 12.0, 13.0, 21.0, 22.0, 23.0];
+/// let (num_bins, gvfs) = suggest_num_bins_elbow(&data, 6);
+///
+/// assert_eq!(num_bins, 3);
+/// assert_eq!(gvfs.len(), 6);
+/// assert_eq!(gvfs[2], 1.0 - 6.0 / 606.0);
+/// ```
+pub fn suggest_num_bins_elbow<T: ToPrimitive>(data: &[T], max_bins: usize) -> (usize, Vec<f64>) {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    let num_unique_vals = unique_val_map.len();
+    let max_bins = core::cmp::max(core::cmp::min(num_unique_vals, max_bins), 1);
+    let mut gvfs: Vec<f64> = vec![0.0];
+    if max_bins == 1 {
+        return (1, gvfs);
+    }
+
+    let gssd = calc_gssd(&sorted_data);
+    let sums = UniqueValSums::new(&unique_val_map);
+
+    let mut costs: Vec<f64> = (0..num_unique_vals).map(|j| sums.ssd(0, j + 1)).collect();
+    let mut bin_starts: Vec<usize> = vec![0; num_unique_vals];
+    for bin in 1..max_bins {
+        costs = next_costs(
+            bin,
+            &costs,
+            &|start, end| sums.ssd(start, end),
+            &mut bin_starts,
+        );
+        gvfs.push(1.0 - costs[num_unique_vals - 1] / gssd);
+    }
+
+    // Distance of each scaled point above the line through the scaled endpoints (0, 0) and (1, 1)
+    let last = max_bins - 1;
+    let mut elbow = 0;
+    let mut max_distance = 0.0;
+    for (i, gvf) in gvfs.iter().enumerate() {
+        let distance = gvf / gvfs[last] - i as f64 / last as f64;
+        if distance > max_distance {
+            max_distance = distance;
+            elbow = i;
+        }
+    }
+
+    (elbow + 1, gvfs)
+}
@@ -0,0 +1,145 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+use num_traits::ToPrimitive;
+
+/// Returns a Classification object following the Geometric Interval Breaks algorithm given the desired number of bins and one-dimensional data
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The algorithm requires strictly positive data, so if the minimum is less than or equal to zero, the data is shifted so that its minimum is 1.0 before computing breaks (and the breaks are shifted back afterwards)
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_geometric_interval_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0];
+/// let num_bins = 4;
+///
+/// let result: Classification = get_geometric_interval_classification(num_bins, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 2.0, count: 1},
+///     Bin{bin_start: 2.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 8.0, count: 2},
+///     Bin{bin_start: 8.0, bin_end: 16.0, count: 3}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_geometric_interval_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Classification {
+    let breaks: Vec<f64> = get_geometric_interval_breaks(num_bins, data);
+    breaks_to_classification(&breaks, data)
+}
+
+/// Returns a Classification object following the Geometric Interval algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_geometric_interval_classification, try_get_geometric_interval_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_geometric_interval_classification(3, &data).unwrap() == get_geometric_interval_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_geometric_interval_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_geometric_interval_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_geometric_interval_classification(num_bins, data))
+}
+
+/// Returns a vector of breaks generated through the Geometric Interval Breaks algorithm given the desired number of bins and a dataset
+///
+/// The width of each bin is a constant multiple of the width of the bin before it, with the ratio chosen so that the last bin ends on the data's maximum
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The algorithm requires strictly positive data, so if the minimum is less than or equal to zero, the data is shifted so that its minimum is 1.0 before computing breaks (and the breaks are shifted back afterwards)
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_geometric_interval_breaks;
+///
+/// let data: Vec<u32> = vec![1, 2, 3, 4, 6, 8, 12, 16];
+/// let num_bins = 4;
+///
+/// let result: Vec<f64> = get_geometric_interval_breaks(num_bins, &data);
+///
+/// assert_eq!(result, vec![2.0, 4.0, 8.0]);
+/// ```
+pub fn get_geometric_interval_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+    let data = to_vec_f64(data);
+
+    let mut min_value = data[0];
+    let mut max_value = data[0];
+    for item in data {
+        if item < min_value {
+            min_value = item;
+        } else if item > max_value {
+            max_value = item;
+        }
+    }
+
+    let mut result: Vec<f64> = vec![];
+    if min_value == max_value {
+        return result;
+    }
+
+    // Shift non-positive data so that the ratio between the minimum and maximum is defined
+    let shift = if min_value <= 0.0 {
+        1.0 - min_value
+    } else {
+        0.0
+    };
+    let ratio = ((max_value + shift) / (min_value + shift)).powf(1.0 / num_bins as f64);
+
+    for i in 1..num_bins {
+        result.push((min_value + shift) * ratio.powi(i as i32) - shift);
+    }
+
+    result
+}
@@ -0,0 +1,110 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+use num_traits::ToPrimitive;
+
+/// Returns a Classification object following the Harmonic Interval Breaks algorithm given the desired number of bins and one-dimensional data
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns errors in the same cases as get_harmonic_interval_breaks
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_harmonic_interval_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f64> = vec![1.0, 1.5, 2.0, 3.0, 4.0];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_harmonic_interval_classification(num_bins, &data).unwrap();
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0 / 3.0, count: 1},
+///     Bin{bin_start: 4.0 / 3.0, bin_end: 2.0, count: 1},
+///     Bin{bin_start: 2.0, bin_end: 4.0, count: 3}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_harmonic_interval_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    let breaks: Vec<f64> = get_harmonic_interval_breaks(num_bins, data)?;
+    Ok(breaks_to_classification(&breaks, data))
+}
+
+/// Returns a vector of breaks whose reciprocals are spaced equally given the desired number of bins and a dataset
+///
+/// The ith break is 1 / (1 / min + i * (1 / max - 1 / min) / num_bins), the reciprocal analogue of logarithmic intervals, so that bins are narrow near the minimum and widen toward the maximum. This suits rate or frequency data measured on a reciprocal scale (such as periods and frequencies)
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+/// * Returns ClassifyError::NonPositiveForHarmonic if any data point is less than or equal to zero (including exactly zero, which has no reciprocal)
+/// * If all data points share the same value, no breaks are generated
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_harmonic_interval_breaks;
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 1.5, 2.0, 3.0, 4.0];
+///
+/// assert_eq!(get_harmonic_interval_breaks(3, &data), Ok(vec![4.0 / 3.0, 2.0]));
+/// assert_eq!(get_harmonic_interval_breaks(3, &[0.0, 1.0]), Err(ClassifyError::NonPositiveForHarmonic));
+/// ```
+pub fn get_harmonic_interval_breaks<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Vec<f64>, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    let data = to_vec_f64(data);
+
+    let mut min_value = data[0];
+    let mut max_value = data[0];
+    for item in &data {
+        if *item <= 0.0 {
+            return Err(ClassifyError::NonPositiveForHarmonic);
+        }
+        if *item < min_value {
+            min_value = *item;
+        } else if *item > max_value {
+            max_value = *item;
+        }
+    }
+
+    let mut result: Vec<f64> = vec![];
+    if min_value == max_value {
+        return Ok(result);
+    }
+
+    let inv_min = 1.0 / min_value;
+    let step = (1.0 / max_value - inv_min) / num_bins as f64;
+    for i in 1..num_bins {
+        result.push(1.0 / (inv_min + i as f64 * step));
+    }
+
+    Ok(result)
+}
@@ -1,3 +1,7 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::utilities::validate_data;
 use crate::utilities::Classification;
 use crate::utilities::{breaks_to_classification, to_vec_f64};
 use num_traits::ToPrimitive;
@@ -11,6 +15,8 @@ use num_traits::ToPrimitive;
 /// # Edge Cases
 ///
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Splitting stops once the head makes up more than 40% of the values it was split from, so near-uniform data produces a single break at the mean
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
 ///
 /// # Examples
 ///
@@ -35,6 +41,37 @@ pub fn get_head_tail_classification<T: ToPrimitive>(data: &[T]) -> Classificatio
     breaks_to_classification(&breaks, data)
 }
 
+/// Returns a Classification object following the Head-Tail Breaks algorithm, returning an error instead of panicking if data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_head_tail_classification, try_get_head_tail_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_head_tail_classification(&data).unwrap() == get_head_tail_classification(&data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_head_tail_classification(&empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_head_tail_classification<T: ToPrimitive>(
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_data(data)?;
+    Ok(get_head_tail_classification(data))
+}
+
 /// Returns a vector of breaks generated through the Head-Tail Breaks algorithm given a dataset
 ///
 /// # Arguments
@@ -44,6 +81,8 @@ pub fn get_head_tail_classification<T: ToPrimitive>(data: &[T]) -> Classificatio
 /// # Edge Cases
 ///
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Splitting stops once the head makes up more than 40% of the values it was split from, so near-uniform data produces a single break at the mean
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
 ///
 /// # Examples
 ///
@@ -73,41 +112,30 @@ pub fn get_head_tail_breaks<T: ToPrimitive>(data: &[T]) -> Vec<f64> {
 }
 
 /// Recursive function used by get_head_tail_breaks that populates a vector of breaks according to the head-tail breaks algorithm
-pub fn head_tail_recursion(data: &Vec<f64>, breaks: &mut Vec<f64>) {
+pub fn head_tail_recursion(data: &[f64], breaks: &mut Vec<f64>) {
+    // Constant data has nothing to split, and its mean may round to just above or below the value
+    if data.is_empty() || data[0] == data[data.len() - 1] {
+        return;
+    }
+
     let mut mean: f64 = 0.0;
     for val in data {
         mean += val
     }
     mean /= data.len() as f64;
 
-    breaks.push(mean);
+    // Binary search to find the first data point greater than or equal to the mean
+    let break_idx = data.partition_point(|val| *val < mean);
 
-    // Binary search to find first data point greater than or equal to the mean
-    let mut low = 0;
-    let mut high = data.len();
-    let mut break_idx = 'outer: loop {
-        let mid = (low + high) / 2;
-        if mean < data[mid] as f64 {
-            high = mid;
-        } else if mean == data[mid] {
-            break 'outer mid;
-        } else if high - low <= 1 {
-            break 'outer high;
-        } else {
-            low = mid;
-        }
-    };
-    // Backtracking to the first occurrence of the target value
-    while break_idx != 0 && data[break_idx] == data[break_idx - 1] {
-        break_idx -= 1;
+    // A rounded mean can lie outside of the data's range, leaving the head or the tail empty
+    if break_idx == 0 || break_idx >= data.len() {
+        return;
     }
+    breaks.push(mean);
 
     let head: Vec<f64> = data[break_idx..].to_vec();
 
-    if !head.is_empty()
-        && (head.len() as f64) / (data.len() as f64) <= 0.4
-        && head[0] != head[head.len() - 1]
-    {
+    if (head.len() as f64) / (data.len() as f64) <= 0.4 && head[0] != head[head.len() - 1] {
         head_tail_recursion(&head, breaks);
     }
 }
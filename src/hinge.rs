@@ -1,5 +1,10 @@
-use crate::utilities::Classification;
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::percentile::{calc_percentile, PercentileInterpolation};
+use crate::utilities::validate_data;
 use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{Bin, Classification};
 use num_traits::ToPrimitive;
 
 /// Returns a Classification object following the Hinge Breaks algorithm given the desired number of bins and one-dimensional data
@@ -43,6 +48,46 @@ pub fn get_hinge_classification<T: ToPrimitive, S: ToPrimitive>(
     breaks_to_classification(&breaks, data)
 }
 
+/// Returns a Classification object following the Hinge Breaks algorithm, returning an error instead of panicking if hinge_coefficient is not finite or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `hinge_coefficient` - A coefficient representing the size of the hinge as a multiple of the data's IQR (usually 1.5 or 3)
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::NonFinite if hinge_coefficient is NaN or infinite
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_hinge_classification, try_get_hinge_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_hinge_classification(1.5, &data).unwrap() == get_hinge_classification(1.5, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_hinge_classification(1.5, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_hinge_classification<T: ToPrimitive, S: ToPrimitive>(
+    hinge_coefficient: S,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    if !hinge_coefficient
+        .to_f64()
+        .map_or(false, |coef| coef.is_finite())
+    {
+        return Err(ClassifyError::NonFinite);
+    }
+    validate_data(data)?;
+    Ok(get_hinge_classification(hinge_coefficient, data))
+}
+
 /// Returns a vector of breaks generated through the Hinge Breaks algorithm given the desired number of bins and a dataset
 ///
 /// # Arguments
@@ -105,8 +150,238 @@ pub fn get_hinge_breaks<T: ToPrimitive, S: ToPrimitive>(
     breaks
 }
 
+/// Returns a Classification object following the Box Plot algorithm given one-dimensional data
+///
+/// The six bins are: lower outliers (below Q1 - 1.5 * IQR), the lower whisker, Q1 to the median, the median to Q3, the upper whisker, and upper outliers (above Q3 + 1.5 * IQR)
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The outlier bins are always present; if there are no outliers on a side, that bin is empty and has zero width (starting and ending on the data's minimum or maximum)
+/// * Values lying exactly on a fence are not outliers and are counted in the neighboring whisker bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_boxplot_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+///
+/// let result: Classification = get_boxplot_classification(&data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 1.0, count: 0},
+///     Bin{bin_start: 1.0, bin_end: 3.0, count: 2},
+///     Bin{bin_start: 3.0, bin_end: 5.0, count: 2},
+///     Bin{bin_start: 5.0, bin_end: 7.0, count: 2},
+///     Bin{bin_start: 7.0, bin_end: 9.0, count: 3},
+///     Bin{bin_start: 9.0, bin_end: 9.0, count: 0}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_boxplot_classification<T: ToPrimitive>(data: &[T]) -> Classification {
+    let (results, _stats) = get_boxplot_classification_with_stats(data);
+    results
+}
+
+/// Returns a Classification object following the Box Plot algorithm, returning an error instead of panicking if data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_boxplot_classification, try_get_boxplot_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_boxplot_classification(&data).unwrap() == get_boxplot_classification(&data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_boxplot_classification(&empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_boxplot_classification<T: ToPrimitive>(
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_data(data)?;
+    Ok(get_boxplot_classification(data))
+}
+
+/// Returns a Classification object following the Box Plot algorithm along with the quartiles and fences used to generate it
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The outlier bins are always present; if there are no outliers on a side, that bin is empty and has zero width (starting and ending on the data's minimum or maximum)
+/// * The fences in the returned BoxplotStats are not clamped to the data's range
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_boxplot_classification_with_stats;
+/// use classify::{Classification, BoxplotStats};
+///
+/// let data: Vec<usize> = vec![0, 1, 10, 11, 12, 13, 14, 15, 16, 20, 25];
+///
+/// let (result, stats): (Classification, BoxplotStats) = get_boxplot_classification_with_stats(&data);
+///
+/// assert_eq!(result.len(), 6);
+/// assert_eq!(stats.q1, 10.5);
+/// assert_eq!(stats.median, 13.0);
+/// assert_eq!(stats.q3, 15.5);
+/// assert_eq!(stats.lower_fence, 3.0);
+/// assert_eq!(stats.upper_fence, 23.0);
+/// ```
+pub fn get_boxplot_classification_with_stats<T: ToPrimitive>(
+    data: &[T],
+) -> (Classification, BoxplotStats) {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let min_val = sorted_data[0];
+    let max_val = sorted_data[sorted_data.len() - 1];
+
+    let stats = BoxplotStats::from_sorted(&sorted_data);
+    let lower_fence = stats.lower_fence.max(min_val);
+    let upper_fence = stats.upper_fence.min(max_val);
+
+    let bounds = [
+        min_val,
+        lower_fence,
+        stats.q1,
+        stats.median,
+        stats.q3,
+        upper_fence,
+        max_val,
+    ];
+    let mut results: Classification = vec![];
+    for i in 0..(bounds.len() - 1) {
+        results.push(Bin {
+            bin_start: bounds[i],
+            bin_end: bounds[i + 1],
+            count: 0,
+        });
+    }
+
+    for val in sorted_data {
+        let idx = if val < stats.lower_fence {
+            0
+        } else if val > stats.upper_fence {
+            5
+        } else if val < stats.q1 {
+            1
+        } else if val < stats.median {
+            2
+        } else if val < stats.q3 {
+            3
+        } else {
+            4
+        };
+        results[idx].count += 1;
+    }
+
+    (results, stats)
+}
+
+/// Represents the quartiles of a dataset along with the fences 1.5 IQRs below Q1 and above Q3 that separate outliers in a box plot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxplotStats {
+    pub q1: f64,
+    pub median: f64,
+    pub q3: f64,
+    pub lower_fence: f64,
+    pub upper_fence: f64,
+}
+
+impl BoxplotStats {
+    /// Calculates box plot statistics for a dataset (sorted, ascending)
+    pub fn from_sorted(sorted_data: &[f64]) -> BoxplotStats {
+        let (q1, median, q3) = quartiles(sorted_data);
+        let iqr = q3 - q1;
+
+        BoxplotStats {
+            q1,
+            median,
+            q3,
+            lower_fence: q1 - 1.5 * iqr,
+            upper_fence: q3 + 1.5 * iqr,
+        }
+    }
+}
+
+/// Calculates the first quartile, median, and third quartile of a dataset (sorted, ascending)
+fn quartiles(sorted_data: &[f64]) -> (f64, f64, f64) {
+    (
+        calc_percentile(25.0, sorted_data, PercentileInterpolation::Linear),
+        calc_percentile(50.0, sorted_data, PercentileInterpolation::Linear),
+        calc_percentile(75.0, sorted_data, PercentileInterpolation::Linear),
+    )
+}
+
+/// Returns the indices of the values in a dataset that lie outside of the fences `k` interquartile ranges below the first and above the third quartile
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to search for outliers
+/// * `k` - The number of interquartile ranges between a quartile and its fence (typically 1.5, or 3.0 for extreme outliers)
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns no indices if data contains no values
+/// * NaN values are ignored when calculating the quartiles and are never flagged as outliers
+/// * The indices are returned in ascending order and refer to positions in the original, unsorted data
+///
+/// # Examples
+///
+/// ```
+/// use classify::detect_outliers_iqr;
+///
+/// let data: Vec<f64> = vec![12.0, 10.0, 11.0, 95.0, 13.0, 14.0, 15.0, -40.0, 16.0];
+///
+/// assert_eq!(detect_outliers_iqr(&data, 1.5), vec![3, 7]);
+/// ```
+pub fn detect_outliers_iqr<T: ToPrimitive>(data: &[T], k: f64) -> Vec<usize> {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.iter().copied().filter(|val| !val.is_nan()).collect();
+    if sorted_data.is_empty() {
+        return vec![];
+    }
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let (q1, _, q3) = quartiles(&sorted_data);
+    let iqr = q3 - q1;
+    let lower_fence = q1 - k * iqr;
+    let upper_fence = q3 + k * iqr;
+
+    data.iter()
+        .enumerate()
+        .filter(|(_, val)| **val < lower_fence || **val > upper_fence)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
 /// Calculates percentiles of a given dataset
-pub fn percentile(perc: u8, data: &Vec<f64>) -> f64 {
+pub fn percentile(perc: u8, data: &[f64]) -> f64 {
     let num_vals = data.len();
 
     let mut sorted_data: Vec<f64> = vec![];
@@ -115,14 +390,5 @@ pub fn percentile(perc: u8, data: &Vec<f64>) -> f64 {
     }
     sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
-    let rank = (perc as f64 / 100.0) * (num_vals as f64 - 1.0);
-
-    if rank as usize == num_vals - 1 {
-        sorted_data[num_vals - 1]
-    } else {
-        let rank_int = rank as usize;
-        let rank_dec = rank - rank_int as f64;
-
-        sorted_data[rank_int] + rank_dec * (sorted_data[rank_int + 1] - sorted_data[rank_int])
-    }
+    calc_percentile(perc as f64, &sorted_data, PercentileInterpolation::Linear)
 }
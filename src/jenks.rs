@@ -1,169 +1,1642 @@
-use num_traits::ToPrimitive;
+use alloc::{vec, vec::Vec};
+
+use num_traits::{Float, ToPrimitive};
 use rand::prelude::*;
 use rand::rngs::StdRng;
 
-use std::collections::HashSet;
+use alloc::borrow::Cow;
+
+use crate::classification::ClassificationExt;
+use crate::error::ClassifyError;
+use crate::percentile::{calc_percentile, PercentileInterpolation};
+use crate::utilities::{apply_nan_policy, Classification, NanPolicy, UniqueVal};
+use crate::utilities::{
+    breaks_to_classification, breaks_to_classification_float, create_unique_val_mapping,
+    to_vec_f64, unique_to_normal_breaks, validate_data, validate_num_bins,
+};
+
+/// Returns a Classification object following the Jenks Natural Breaks algorithm given the desired number of bins and one-dimensional data
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///      
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset, and every bin contains at least one data point. If num_bins is at least the number of unique values, each unique value gets its own bin, with the last bin starting and ending on the maximum
+/// * Panics if num_bins is 0 or data contains no values or values that are not finite (use try_get_jenks_classification to get an error instead)
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_jenks_classification;
+/// use classify::{Classification, Bin};
+/// use rand::prelude::*;
+/// use rand::rngs::StdRng;
+///
+/// let data: Vec<usize> = vec![1, 2, 4, 5, 7, 8];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_jenks_classification(num_bins, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 7.0, count: 2},
+///     Bin{bin_start: 7.0, bin_end: 8.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_jenks_classification<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Classification {
+    try_get_jenks_classification(num_bins, data).unwrap()
+}
+
+/// Returns a Classification object following the Jenks Natural Breaks algorithm given the desired number of bins and data points from any iterator, such as a streaming source
+///
+/// Jenks needs the full sorted dataset, so the data points are collected internally (see get_quantile_classification_streaming for a method that reads a stream in bounded memory)
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - Any iterator or collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Panics if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, get_jenks_classification_iter};
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+///
+/// let result = get_jenks_classification_iter(3, data.iter().copied());
+///
+/// assert!(result == get_jenks_classification(3, &data));
+/// ```
+pub fn get_jenks_classification_iter<I: IntoIterator<Item = f64>>(
+    num_bins: usize,
+    data: I,
+) -> Classification {
+    let data: Vec<f64> = data.into_iter().collect();
+    get_jenks_classification(num_bins, &data)
+}
+
+/// Returns a Classification object following the Jenks Natural Breaks algorithm given the desired number of bins and one-dimensional data, or an error if one cannot be generated
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset, and every bin contains at least one data point. If num_bins is at least the number of unique values, each unique value gets its own bin, with the last bin starting and ending on the maximum
+/// * Returns ClassifyError::ZeroBins if num_bins is 0, while a num_bins of 1 produces a single bin spanning the data's range
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values (see JenksConfig to drop them instead)
+///
+/// # Examples
+///
+/// ```
+/// use classify::try_get_jenks_classification;
+/// use classify::ClassifyError;
+///
+/// let data: Vec<usize> = vec![1, 2, 4, 5, 7, 8];
+/// assert_eq!(try_get_jenks_classification(3, &data).unwrap().len(), 3);
+///
+/// let empty: Vec<usize> = vec![];
+/// assert_eq!(try_get_jenks_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_jenks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    JenksConfig::new().classify(num_bins, data)
+}
+
+/// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm given the desired number of bins and a dataset
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Panics if num_bins is 0 or data contains no values or values that are not finite (use try_get_jenks_breaks to get an error instead)
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_jenks_breaks;
+/// use rand::prelude::*;
+/// use rand::rngs::StdRng;
+///
+/// let data: Vec<i8> = vec![1, 2, 4, 5, 7, 8];
+/// let num_bins = 3;
+///
+/// let result: Vec<f64> = get_jenks_breaks(num_bins, &data);
+///
+/// assert_eq!(result, vec![4.0, 7.0]);
+/// ```
+pub fn get_jenks_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+    try_get_jenks_breaks(num_bins, data).unwrap()
+}
+
+/// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm given the desired number of bins and a dataset, or an error if they cannot be generated
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Returns ClassifyError::ZeroBins if num_bins is 0, while a num_bins of 1 produces a single bin spanning the data's range
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values (see JenksConfig to drop them instead)
+///
+/// # Examples
+///
+/// ```
+/// use classify::try_get_jenks_breaks;
+/// use classify::ClassifyError;
+///
+/// let data: Vec<i8> = vec![1, 2, 4, 5, 7, 8];
+/// assert_eq!(try_get_jenks_breaks(3, &data), Ok(vec![4.0, 7.0]));
+///
+/// let empty: Vec<i8> = vec![];
+/// assert_eq!(try_get_jenks_breaks(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_jenks_breaks<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Vec<f64>, ClassifyError> {
+    JenksConfig::new().breaks(num_bins, data)
+}
+
+/// Returns a Classification object following the Jenks Natural Breaks algorithm along with the goodness of variance fit (GVF) of its breaks
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * The GVF is calculated for the returned breaks, ranging from 0 (no better than a single bin) to 1 (every bin contains a single unique value)
+/// * Panics if num_bins is 0 or data contains no values or values that are not finite (use try_get_jenks_classification to get an error instead)
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_jenks_classification_with_gvf;
+///
+/// let data: Vec<usize> = vec![1, 2, 4, 5, 7, 8];
+///
+/// let (result, gvf) = get_jenks_classification_with_gvf(3, &data);
+///
+/// assert_eq!(result.len(), 3);
+/// assert_eq!(gvf, 0.96);
+/// ```
+pub fn get_jenks_classification_with_gvf<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> (Classification, f64) {
+    let data = to_vec_f64(data);
+    let (breaks, stats) = jenks_breaks_and_stats(
+        num_bins,
+        &data,
+        &JenksConfig::new(),
+        BreakSampler::<StdRng>::Seeded(DEFAULT_SEED),
+    )
+    .unwrap();
+    (breaks_to_classification(&breaks, &data), stats.gvf)
+}
+
+/// Floating point types the Jenks functions accept, which are all Float types, also required to be Send + Sync with the "rayon" feature so permutations can be spread across threads
+#[cfg(feature = "rayon")]
+pub trait JenksFloat: Float + Send + Sync {}
+#[cfg(feature = "rayon")]
+impl<F: Float + Send + Sync> JenksFloat for F {}
+
+/// Floating point types the Jenks functions accept, which are all Float types, also required to be Send + Sync with the "rayon" feature so permutations can be spread across threads
+#[cfg(not(feature = "rayon"))]
+pub trait JenksFloat: Float {}
+#[cfg(not(feature = "rayon"))]
+impl<F: Float> JenksFloat for F {}
+
+/// Returns a Classification object following the Jenks Natural Breaks algorithm given the desired number of bins and floating point data, keeping the data's type (such as f32) instead of converting it to f64
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted floating point data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Panics if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_jenks_classification_float;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f32> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let num_bins = 3;
+///
+/// let result: Classification<f32> = get_jenks_classification_float(num_bins, &data);
+/// let expected: Classification<f32> = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 7.0, count: 2},
+///     Bin{bin_start: 7.0, bin_end: 8.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_jenks_classification_float<F: JenksFloat>(
+    num_bins: usize,
+    data: &[F],
+) -> Classification<F> {
+    let breaks: Vec<F> = get_jenks_breaks_float(num_bins, data);
+    breaks_to_classification_float(&breaks, data)
+}
+
+/// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm given the desired number of bins and a floating point dataset, keeping the data's type (such as f32) instead of converting it to f64
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted floating point data points to generate breaks for
+///
+/// # Edge Cases
+///
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Panics if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_jenks_breaks_float;
+///
+/// let data: Vec<f32> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let num_bins = 3;
+///
+/// let result: Vec<f32> = get_jenks_breaks_float(num_bins, &data);
+///
+/// assert_eq!(result, vec![4.0, 7.0]);
+/// ```
+pub fn get_jenks_breaks_float<F: JenksFloat>(num_bins: usize, data: &[F]) -> Vec<F> {
+    if data.iter().any(|val| !val.is_finite()) {
+        panic!("{}", ClassifyError::NonFinite);
+    }
+    let config = JenksConfig::new();
+    let (breaks, _stats) = jenks_breaks_and_stats(
+        num_bins,
+        data,
+        &config,
+        BreakSampler::<StdRng>::Seeded(config.seed),
+    )
+    .unwrap();
+    breaks
+}
+
+/// Configures a run of the Jenks Natural Breaks algorithm
+///
+/// Jenks samples random sets of breaks and keeps the one with the best goodness of variance fit, so more permutations trade speed for quality, while a fixed seed makes the results reproducible
+///
+/// # Examples
+///
+/// ```
+/// use classify::{JenksConfig, NanPolicy};
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, f64::NAN, 4.0, 5.0, 7.0, 8.0];
+///
+/// let result: Classification = JenksConfig::new()
+///     .nan_policy(NanPolicy::Drop)
+///     .classify(3, &data)
+///     .unwrap();
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 7.0, count: 2},
+///     Bin{bin_start: 7.0, bin_end: 8.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+#[derive(Debug, Clone)]
+pub struct JenksConfig {
+    permutations: Option<usize>,
+    patience: Option<usize>,
+    seed: u64,
+    nan_policy: NanPolicy,
+    assume_sorted: bool,
+}
+
+impl Default for JenksConfig {
+    fn default() -> Self {
+        JenksConfig {
+            permutations: None,
+            patience: None,
+            seed: DEFAULT_SEED,
+            nan_policy: NanPolicy::default(),
+            assume_sorted: false,
+        }
+    }
+}
+
+impl JenksConfig {
+    /// Creates a configuration with the default settings used by get_jenks_classification
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of random sets of breaks to evaluate (defaults to 44,000,000 divided by the number of data points, kept between 10 and 10,000); at least one permutation is always run
+    ///
+    /// If the data's unique values allow at most 10,000 different sets of breaks, every set is evaluated instead of sampling, so the breaks are optimal and do not depend on the seed or on the number of permutations
+    pub fn permutations(mut self, permutations: usize) -> Self {
+        self.permutations = Some(permutations);
+        self
+    }
+
+    /// Sets a patience after which sampling stops early: once this many consecutive permutations (at least 1) have not improved on the best GVF found so far, the best breaks are returned
+    ///
+    /// Without a patience, the number of permutations is fixed. With one, it adapts to how hard the data is to classify, and is only capped if JenksConfig::permutations is also set. Runs with a patience always sample serially, including with the "rayon" feature, and the number of permutations actually run is reported by JenksConfig::classify_with_stats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::JenksConfig;
+    ///
+    /// let data: Vec<f64> = (0..1000).map(|i| ((i * 37 % 101) as f64).powf(1.3)).collect();
+    ///
+    /// let (result, stats) = JenksConfig::new().patience(200).classify_with_stats(4, &data).unwrap();
+    ///
+    /// assert_eq!(result.len(), 4);
+    /// assert!(stats.permutations >= 200);
+    /// ```
+    pub fn patience(mut self, patience: usize) -> Self {
+        self.patience = Some(patience.max(1));
+        self
+    }
+
+    /// Sets the seed of the random number generator used to sample breaks (defaults to 123456789), which is ignored by the methods that take their own random number generator
+    ///
+    /// Each permutation draws from its own generator seeded from this seed and the permutation's index, so the same seed gives the same breaks with or without the "rayon" feature (which spreads permutations across threads) and regardless of the number of threads
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets how data points that are NaN or infinite are handled (defaults to NanPolicy::Error)
+    pub fn nan_policy(mut self, nan_policy: NanPolicy) -> Self {
+        self.nan_policy = nan_policy;
+        self
+    }
+
+    /// Sets whether the data is already sorted in ascending order, which skips sorting a copy of it (defaults to false)
+    ///
+    /// Debug builds panic if the data turns out not to be sorted, while release builds produce meaningless breaks
+    pub fn assume_sorted(mut self, assume_sorted: bool) -> Self {
+        self.assume_sorted = assume_sorted;
+        self
+    }
+
+    /// Returns a Classification object following the Jenks Natural Breaks algorithm given the desired number of bins and one-dimensional data, or an error if one cannot be generated
+    ///
+    /// Data points dropped through NanPolicy::Drop are not counted in any bin
+    pub fn classify<T: ToPrimitive>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+    ) -> Result<Classification, ClassifyError> {
+        Ok(self.classify_with_stats(num_bins, data)?.0)
+    }
+
+    /// Returns a Classification object following the Jenks Natural Breaks algorithm along with the GVF of its breaks and the number of permutations run, or an error if one cannot be generated
+    pub fn classify_with_stats<T: ToPrimitive>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+    ) -> Result<(Classification, JenksStats), ClassifyError> {
+        let data = apply_nan_policy(to_vec_f64(data), self.nan_policy)?;
+        let sampler = BreakSampler::<StdRng>::Seeded(self.seed);
+        let (breaks, stats) = jenks_breaks_and_stats(num_bins, &data, self, sampler)?;
+        Ok((breaks_to_classification(&breaks, &data), stats))
+    }
+
+    /// Returns a Classification object following the Jenks Natural Breaks algorithm, sampling breaks from the given random number generator instead of one seeded with the configured seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::JenksConfig;
+    /// use rand::prelude::*;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let result = JenksConfig::new().classify_with_rng(3, &data, &mut rng).unwrap();
+    ///
+    /// assert_eq!(result.len(), 3);
+    /// ```
+    pub fn classify_with_rng<T: ToPrimitive, R: Rng + ?Sized>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+        rng: &mut R,
+    ) -> Result<Classification, ClassifyError> {
+        let data = apply_nan_policy(to_vec_f64(data), self.nan_policy)?;
+        let (breaks, _stats) =
+            jenks_breaks_and_stats(num_bins, &data, self, BreakSampler::Rng(rng))?;
+        Ok(breaks_to_classification(&breaks, &data))
+    }
+
+    /// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm given the desired number of bins and a dataset, or an error if they cannot be generated
+    pub fn breaks<T: ToPrimitive>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+    ) -> Result<Vec<f64>, ClassifyError> {
+        let data = apply_nan_policy(to_vec_f64(data), self.nan_policy)?;
+        let sampler = BreakSampler::<StdRng>::Seeded(self.seed);
+        let (breaks, _stats) = jenks_breaks_and_stats(num_bins, &data, self, sampler)?;
+        Ok(breaks)
+    }
+
+    /// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm, sampling them from the given random number generator instead of one seeded with the configured seed
+    pub fn breaks_with_rng<T: ToPrimitive, R: Rng + ?Sized>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+        rng: &mut R,
+    ) -> Result<Vec<f64>, ClassifyError> {
+        let data = apply_nan_policy(to_vec_f64(data), self.nan_policy)?;
+        let (breaks, _stats) =
+            jenks_breaks_and_stats(num_bins, &data, self, BreakSampler::Rng(rng))?;
+        Ok(breaks)
+    }
+}
+
+/// Returns a Classification object following the Jenks Natural Breaks algorithm given the desired number of bins and frequency-weighted one-dimensional data, without expanding each value into copies
+///
+/// Each value counts as if it occurred as many times as its weight, so the breaks maximize the weighted goodness of variance fit
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `values` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `weights` - A reference to a collection of non-negative weights (e.g. frequencies), one per value
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Each bin's count is the sum of the weights of its values rounded to the nearest integer
+/// * Values with a weight of 0 are ignored, and the maximum number of bins generated by this algorithm is the number of unique values with a positive weight
+/// * Panics in the same cases that try_get_weighted_jenks_classification returns an error
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_weighted_jenks_classification;
+/// use classify::{Classification, Bin};
+///
+/// let values: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let weights: Vec<f64> = vec![500.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+///
+/// let result: Classification = get_weighted_jenks_classification(2, &values, &weights);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0, count: 501},
+///     Bin{bin_start: 4.0, bin_end: 8.0, count: 4}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_weighted_jenks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    values: &[T],
+    weights: &[f64],
+) -> Classification {
+    try_get_weighted_jenks_classification(num_bins, values, weights).unwrap()
+}
+
+/// Returns a Classification object following the Jenks Natural Breaks algorithm given the desired number of bins and frequency-weighted one-dimensional data, or an error if one cannot be generated
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `values` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `weights` - A reference to a collection of non-negative weights (e.g. frequencies), one per value
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::InvalidWeights if there is not exactly one weight per value or any weight is negative or not finite
+/// * Returns ClassifyError::NonFinite if values contains NaN or infinite values
+/// * Returns ClassifyError::EmptyData if no value has a positive weight
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, try_get_weighted_jenks_classification};
+/// use classify::ClassifyError;
+///
+/// let values: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let ones: Vec<f64> = vec![1.0; 6];
+/// assert!(try_get_weighted_jenks_classification(3, &values, &ones).unwrap() == get_jenks_classification(3, &values));
+///
+/// assert_eq!(try_get_weighted_jenks_classification(3, &values, &[1.0]), Err(ClassifyError::InvalidWeights));
+/// ```
+pub fn try_get_weighted_jenks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    values: &[T],
+    weights: &[f64],
+) -> Result<Classification, ClassifyError> {
+    let (vals, val_weights) = weighted_unique_vals(num_bins, values, weights)?;
+    let breaks = weighted_jenks_breaks(num_bins, &vals, &val_weights, &JenksConfig::new());
+
+    let mut results = breaks_to_classification(&breaks, &vals);
+    let mut bin_weights: Vec<f64> = vec![0.0; results.len()];
+    for (val, weight) in vals.iter().zip(&val_weights) {
+        if let Some(idx) = results.classify(*val) {
+            bin_weights[idx] += weight;
+        }
+    }
+    for (bin, weight) in results.iter_mut().zip(bin_weights) {
+        bin.count = weight.round() as u64;
+    }
+
+    Ok(results)
+}
+
+/// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm given the desired number of bins and frequency-weighted data
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `values` - A reference to a collection of unsorted data points to generate breaks for
+/// * `weights` - A reference to a collection of non-negative weights (e.g. frequencies), one per value
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Values with a weight of 0 are ignored, and the maximum number of bins generated by this algorithm is the number of unique values with a positive weight
+/// * Panics in the same cases that try_get_weighted_jenks_classification returns an error
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_weighted_jenks_breaks;
+///
+/// let values: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let weights: Vec<f64> = vec![500.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+///
+/// assert_eq!(get_weighted_jenks_breaks(2, &values, &weights), vec![4.0]);
+/// ```
+pub fn get_weighted_jenks_breaks<T: ToPrimitive>(
+    num_bins: usize,
+    values: &[T],
+    weights: &[f64],
+) -> Vec<f64> {
+    let (vals, val_weights) = weighted_unique_vals(num_bins, values, weights).unwrap();
+    weighted_jenks_breaks(num_bins, &vals, &val_weights, &JenksConfig::new())
+}
+
+/// Validates frequency-weighted data and merges it into its unique values (sorted, ascending) with summed weights, leaving out values with a weight of 0
+fn weighted_unique_vals<T: ToPrimitive>(
+    num_bins: usize,
+    values: &[T],
+    weights: &[f64],
+) -> Result<(Vec<f64>, Vec<f64>), ClassifyError> {
+    if num_bins == 0 {
+        return Err(ClassifyError::ZeroBins);
+    }
+    if values.len() != weights.len()
+        || weights
+            .iter()
+            .any(|weight| !weight.is_finite() || *weight < 0.0)
+    {
+        return Err(ClassifyError::InvalidWeights);
+    }
+    let values = to_vec_f64(values);
+    if values.iter().any(|val| !val.is_finite()) {
+        return Err(ClassifyError::NonFinite);
+    }
+
+    let mut pairs: Vec<(f64, f64)> = values
+        .into_iter()
+        .zip(weights.iter().copied())
+        .filter(|(_, weight)| *weight > 0.0)
+        .collect();
+    if pairs.is_empty() {
+        return Err(ClassifyError::EmptyData);
+    }
+    pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let mut vals: Vec<f64> = vec![];
+    let mut val_weights: Vec<f64> = vec![];
+    for (val, weight) in pairs {
+        if vals.last() == Some(&val) {
+            *val_weights.last_mut().unwrap() += weight;
+        } else {
+            vals.push(val);
+            val_weights.push(weight);
+        }
+    }
+
+    Ok((vals, val_weights))
+}
+
+/// Generates Jenks Natural Breaks for unique values (sorted, ascending) carrying positive weights
+fn weighted_jenks_breaks(
+    num_bins: usize,
+    vals: &[f64],
+    weights: &[f64],
+    config: &JenksConfig,
+) -> Vec<f64> {
+    let num_vals = vals.len();
+    let true_num_bins = core::cmp::min(num_vals, num_bins);
+    if true_num_bins <= 1 {
+        return vec![];
+    }
+
+    // Every value is unique, so each one maps onto its own index
+    let unique_val_map: Vec<UniqueVal> = (0..num_vals)
+        .map(|i| UniqueVal {
+            val: vals[i],
+            first: i,
+            last: i,
+        })
+        .collect();
+    let gssd = calc_weighted_gssd(vals, weights);
+    let sums = PrefixSums::weighted(vals, weights);
+
+    let permutations = config
+        .permutations
+        .unwrap_or_else(|| default_permutations(num_vals))
+        .max(1);
+    let (best_breaks, _permutations_run) = sample_breaks::<f64, StdRng>(
+        true_num_bins - 1,
+        permutations,
+        None,
+        &unique_val_map,
+        &sums,
+        &gssd,
+        BreakSampler::Seeded(config.seed),
+    );
+
+    strictly_increasing_breaks(best_breaks.iter().map(|idx| vals[*idx]), vals[0])
+}
 
-use crate::utilities::{
-    breaks_to_classification, create_unique_val_mapping, to_vec_f64, unique_to_normal_breaks,
-};
-use crate::utilities::{Classification, UniqueVal};
+/// Collects break values into ascending order, dropping any break that repeats another or lies at or below the data's minimum, so that no bin of the resulting Classification is empty or has zero width
+///
+/// Each dropped break merges two bins, so the Classification has fewer bins than requested
+fn strictly_increasing_breaks<F: Float>(breaks: impl Iterator<Item = F>, min: F) -> Vec<F> {
+    let mut breaks: Vec<F> = breaks.collect();
+    breaks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut lower = min;
+    breaks.retain(|brk| {
+        let keep = *brk > lower;
+        if keep {
+            lower = *brk;
+        }
+        keep
+    });
+    breaks
+}
 
-/// Returns a Classification object following the Jenks Natural Breaks algorithm given the desired number of bins and one-dimensional data
+/// Returns a Classification object following the Jenks Natural Breaks algorithm with outliers left out of the break computation, along with the number of data points treated as outliers
+///
+/// The lowest and highest `trim_fraction` of the data points are excluded when computing breaks, so extreme values do not drag breaks toward the tails. They are then counted in the first and last bins, which extend to the data's minimum and maximum
 ///
 /// # Arguments
 ///
 /// * `num_bins` - An integer (usize) representing the desired number of bins
 /// * `data` - A reference to a collection of unsorted data points to generate a Classification for
-///      
+/// * `trim_fraction` - The share of data points (from 0 to 0.5) to exclude at each end of the data
+///
 /// # Edge Cases
 ///
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
-/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * The number of data points trimmed at each end is `trim_fraction` times the number of data points, rounded down. A negative or NaN trim_fraction trims nothing, and at least one data point is always kept
+/// * The maximum number of bins generated by this algorithm is the number of unique values among the kept data points
+/// * Panics in the same cases that try_get_trimmed_jenks_classification returns an error
 ///
 /// # Examples
 ///
 /// ```
-/// use classify::get_jenks_classification;
+/// use classify::get_trimmed_jenks_classification;
 /// use classify::{Classification, Bin};
-/// use rand::prelude::*;
-/// use rand::rngs::StdRng;
 ///
-/// let data: Vec<usize> = vec![1, 2, 4, 5, 7, 8];
-/// let num_bins = 3;
+/// let data: Vec<f64> = vec![-1000.0, 1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 1000.0];
 ///
-/// let result: Classification = get_jenks_classification(num_bins, &data);
+/// let (result, num_outliers) = get_trimmed_jenks_classification(3, &data, 0.125);
 /// let expected: Classification = vec![
-///     Bin{bin_start: 1.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: -1000.0, bin_end: 4.0, count: 3},
 ///     Bin{bin_start: 4.0, bin_end: 7.0, count: 2},
-///     Bin{bin_start: 7.0, bin_end: 8.0, count: 2}
+///     Bin{bin_start: 7.0, bin_end: 1000.0, count: 3}
 /// ];
 ///
 /// assert!(result == expected);
+/// assert_eq!(num_outliers, 2);
 /// ```
-pub fn get_jenks_classification<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Classification {
-    let breaks: Vec<f64> = get_jenks_breaks(num_bins, data);
-    breaks_to_classification(&breaks, data)
+pub fn get_trimmed_jenks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    trim_fraction: f64,
+) -> (Classification, usize) {
+    try_get_trimmed_jenks_classification(num_bins, data, trim_fraction).unwrap()
 }
 
-/// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm given the desired number of bins and a dataset
+/// Returns a Classification object following the Jenks Natural Breaks algorithm with outliers left out of the break computation, along with the number of data points treated as outliers, or an error if one cannot be generated
 ///
 /// # Arguments
 ///
-/// * `num_bins` - The desired number of bins
-/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `trim_fraction` - The share of data points (from 0 to 0.5) to exclude at each end of the data
 ///
 /// # Edge Cases
 ///
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
-/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
 ///
 /// # Examples
 ///
 /// ```
-/// use classify::get_jenks_breaks;
-/// use rand::prelude::*;
-/// use rand::rngs::StdRng;
+/// use classify::{get_jenks_classification, try_get_trimmed_jenks_classification};
+/// use classify::ClassifyError;
 ///
-/// let data: Vec<i8> = vec![1, 2, 4, 5, 7, 8];
-/// let num_bins = 3;
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// assert_eq!(try_get_trimmed_jenks_classification(3, &data, 0.0), Ok((get_jenks_classification(3, &data), 0)));
 ///
-/// let result: Vec<f64> = get_jenks_breaks(num_bins, &data);
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_trimmed_jenks_classification(3, &empty, 0.1), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_trimmed_jenks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    trim_fraction: f64,
+) -> Result<(Classification, usize), ClassifyError> {
+    if num_bins == 0 {
+        return Err(ClassifyError::ZeroBins);
+    }
+    let mut sorted_data = apply_nan_policy(to_vec_f64(data), NanPolicy::Error)?;
+    if sorted_data.is_empty() {
+        return Err(ClassifyError::EmptyData);
+    }
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let num_vals = sorted_data.len();
+    let trim_fraction = if trim_fraction > 0.0 {
+        trim_fraction
+    } else {
+        0.0
+    };
+    let num_trimmed = core::cmp::min(
+        (trim_fraction * num_vals as f64) as usize,
+        (num_vals - 1) / 2,
+    );
+
+    let kept = &sorted_data[num_trimmed..num_vals - num_trimmed];
+    let breaks = JenksConfig::new()
+        .assume_sorted(true)
+        .breaks(num_bins, kept)?;
+
+    Ok((
+        breaks_to_classification(&breaks, &sorted_data),
+        2 * num_trimmed,
+    ))
+}
+
+/// Returns a confidence interval (from the 5th to the 95th percentile) for each Jenks Natural Breaks break, estimated by rerunning get_jenks_breaks on resamples of the data drawn with replacement
+///
+/// Wide intervals indicate that the breaks depend heavily on the particular data points and that the classification is fragile
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to assess breaks for
+/// * `iterations` - The number of resamples to draw
+/// * `seed` - The seed of the random number generator used to draw the resamples
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Resamples with too few unique values to place every break are left out, and if no resample places every break (or data contains no values or values that are not finite), no intervals are returned
+/// * Each resample runs the full Jenks Natural Breaks algorithm, so large numbers of iterations on big datasets are slow
+///
+/// # Examples
 ///
-/// assert_eq!(result, vec![4.0, 7.0]);
 /// ```
-pub fn get_jenks_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+/// use classify::bootstrap_break_stability;
+///
+/// let data: Vec<f64> = vec![1.0, 1.5, 2.0, 2.5, 20.0, 20.5, 21.0, 21.5];
+///
+/// let intervals: Vec<(f64, f64)> = bootstrap_break_stability(2, &data, 50, 42);
+///
+/// assert_eq!(intervals.len(), 1);
+/// assert!(2.5 < intervals[0].0 && intervals[0].1 <= 21.5);
+/// ```
+pub fn bootstrap_break_stability<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    iterations: usize,
+    seed: u64,
+) -> Vec<(f64, f64)> {
     let data = to_vec_f64(data);
+    let num_breaks = num_bins.saturating_sub(1);
+    if data.is_empty() || num_breaks == 0 || data.iter().any(|val| !val.is_finite()) {
+        return vec![];
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut samples: Vec<Vec<f64>> = vec![vec![]; num_breaks];
+    let mut resample: Vec<f64> = vec![0.0; data.len()];
+    for _ in 0..iterations {
+        for val in resample.iter_mut() {
+            *val = data[rng.gen_range(0..data.len())];
+        }
+        let breaks = get_jenks_breaks(num_bins, &resample);
+        if breaks.len() == num_breaks {
+            for (i, brk) in breaks.into_iter().enumerate() {
+                samples[i].push(brk);
+            }
+        }
+    }
+
+    if samples[0].is_empty() {
+        return vec![];
+    }
+    samples
+        .iter_mut()
+        .map(|positions| {
+            positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            (
+                calc_percentile(5.0, positions, PercentileInterpolation::Linear),
+                calc_percentile(95.0, positions, PercentileInterpolation::Linear),
+            )
+        })
+        .collect()
+}
 
+/// Returns the leave-one-out (jackknife) influence of each observation on the Jenks Natural Breaks of a dataset, as the total distance the breaks move when the observation is removed
+///
+/// Observations with a high influence are candidate outliers or leverage points. The dataset is sorted once, and observations sharing a value are only removed once since they have the same influence
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to assess breaks for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The influences are returned in the order of data, and an empty dataset returns no influences
+/// * Every influence is 0 if num_bins is 0 or 1 or data contains a single value
+/// * If removing an observation leaves fewer unique values than breaks, only the breaks that remain are compared
+/// * Jenks samples its breaks, so on large datasets part of an influence may come from sampling rather than from the observation itself
+/// * Panics if data contains values that are not finite
+///
+/// # Examples
+///
+/// ```
+/// use classify::jackknife_break_influence;
+///
+/// let data: Vec<f64> = vec![1.0, 1.5, 2.0, 2.5, 20.0, 20.5, 21.0, 21.5, 100.0];
+///
+/// let influence: Vec<f64> = jackknife_break_influence(3, &data);
+///
+/// assert_eq!(influence.len(), data.len());
+/// assert_eq!(influence[0], 0.0);
+/// assert!(influence[8] > 50.0);
+/// ```
+pub fn jackknife_break_influence<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
     let num_vals = data.len();
+    if num_bins <= 1 || num_vals <= 1 {
+        return vec![0.0; num_vals];
+    }
 
-    let mut sorted_data: Vec<f64> = vec![];
-    for item in data.iter().take(num_vals) {
-        sorted_data.push(*item);
+    let prepared = PreparedData::new(data);
+    let base_breaks = prepared.jenks_breaks(num_bins);
+
+    let vals = to_vec_f64(data);
+    let mut order: Vec<usize> = (0..num_vals).collect();
+    order.sort_by(|a, b| vals[*a].partial_cmp(&vals[*b]).unwrap());
+
+    let mut influence: Vec<f64> = vec![0.0; num_vals];
+    for unique_val in prepared.unique_val_map.iter() {
+        let breaks = prepared.without(unique_val.first).jenks_breaks(num_bins);
+        let displacement: f64 = base_breaks
+            .iter()
+            .zip(breaks.iter())
+            .map(|(base, brk)| (brk - base).abs())
+            .sum();
+        for idx in order[unique_val.first..=unique_val.last].iter() {
+            influence[*idx] = displacement;
+        }
     }
+
+    influence
+}
+
+/// Returns the share of random sets of breaks whose goodness of variance fit (GVF) on a dataset meets or exceeds that of a Classification, as a Monte Carlo estimate of how likely the Classification's fit is to arise by chance
+///
+/// The random breaks are drawn in the same way as the candidate breaks of get_jenks_classification (uniformly among the dataset's unique values), with as many breaks as the Classification has. A result near 0 means the Classification captures structure in the data that random breaks rarely match
+///
+/// # Arguments
+///
+/// * `classification` - A reference to the Classification to test, generated from `data` through any method
+/// * `data` - A reference to a collection of unsorted data points
+/// * `trials` - The number of random sets of breaks to draw
+/// * `seed` - The seed of the random number generator used to draw the breaks
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns 1.0 if there are no trials, if the Classification has a single bin, or if the dataset has too few unique values for the Classification's number of breaks
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, gvf_significance};
+///
+/// let data: Vec<f64> = (0..60).map(|i| (i / 20) as f64 * 10.0 + (i % 20) as f64 * 0.1).collect();
+/// let classification = get_jenks_classification(3, &data);
+///
+/// assert!(gvf_significance(&classification, &data, 200, 42) < 0.05);
+/// ```
+pub fn gvf_significance<T: ToPrimitive>(
+    classification: &Classification,
+    data: &[T],
+    trials: usize,
+    seed: u64,
+) -> f64 {
+    let mut sorted_data = to_vec_f64(data);
     sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
     let mut unique_val_map: Vec<UniqueVal> = vec![];
     create_unique_val_mapping(&mut unique_val_map, &sorted_data);
 
+    let num_breaks = classification.len().saturating_sub(1);
     let num_unique_vals = unique_val_map.len();
-    let true_num_bins = std::cmp::min(num_unique_vals, num_bins);
+    if trials == 0 || num_breaks == 0 || num_breaks >= num_unique_vals {
+        return 1.0;
+    }
 
     let gssd = calc_gssd(&sorted_data);
+    let sums = PrefixSums::new(&sorted_data);
+    let class_breaks: Vec<usize> = classification
+        .breaks()
+        .iter()
+        .map(|brk| sorted_data.partition_point(|val| val < brk))
+        .collect();
+    let class_gvf = sums.gvf(&class_breaks, &gssd);
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut unique_rand_breaks: Vec<usize> = vec![0_usize; num_breaks];
+    let mut rand_breaks: Vec<usize> = vec![0_usize; num_breaks];
+    let mut num_at_least = 0;
+    for _ in 0..trials {
+        pick_rand_breaks(&mut unique_rand_breaks, &num_unique_vals, &mut rng);
+        unique_to_normal_breaks(&unique_rand_breaks, &unique_val_map, &mut rand_breaks);
+        if sums.gvf(&rand_breaks, &gssd) >= class_gvf {
+            num_at_least += 1;
+        }
+    }
+
+    num_at_least as f64 / trials as f64
+}
+
+/// Holds a dataset prepared once for repeated runs of the Jenks Natural Breaks algorithm, such as when sweeping the number of bins
+///
+/// Sorting the data, mapping its unique values, and calculating its cumulative sums and GSSD are done when the PreparedData is built rather than on every run
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_breaks, PreparedData};
+///
+/// let data: Vec<i8> = vec![8, 1, 5, 2, 7, 4];
+/// let prepared = PreparedData::new(&data);
+///
+/// assert_eq!(prepared.jenks_breaks(3), vec![4.0, 7.0]);
+/// for num_bins in 1..=6 {
+///     assert_eq!(prepared.jenks_breaks(num_bins), get_jenks_breaks(num_bins, &data));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreparedData {
+    sorted_data: Vec<f64>,
+    unique_val_map: Vec<UniqueVal>,
+    sums: PrefixSums<f64>,
+    gssd: f64,
+}
+
+impl PreparedData {
+    /// Prepares a collection of unsorted data points for repeated Jenks runs
+    ///
+    /// # Edge Cases
+    ///
+    /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+    /// * Panics if data contains no values or values that are not finite (use PreparedData::try_new to get an error instead)
+    pub fn new<T: ToPrimitive>(data: &[T]) -> Self {
+        PreparedData::try_new(data).unwrap()
+    }
+
+    /// Prepares a collection of unsorted data points for repeated Jenks runs, or returns an error if it cannot be classified
+    ///
+    /// # Edge Cases
+    ///
+    /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+    /// * Returns ClassifyError::EmptyData if data contains no values
+    /// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+    pub fn try_new<T: ToPrimitive>(data: &[T]) -> Result<Self, ClassifyError> {
+        validate_data(data)?;
+
+        let mut sorted_data = to_vec_f64(data);
+        sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Ok(PreparedData::from_sorted(sorted_data))
+    }
+
+    /// Prepares data points that are already sorted in ascending order
+    fn from_sorted(sorted_data: Vec<f64>) -> Self {
+        let mut unique_val_map: Vec<UniqueVal> = vec![];
+        create_unique_val_mapping(&mut unique_val_map, &sorted_data);
 
-    let mut rand_breaks: Vec<usize> = vec![0_usize; true_num_bins - 1];
-    let mut best_breaks: Vec<usize> = vec![0_usize; true_num_bins - 1];
-    let mut unique_rand_breaks: Vec<usize> = vec![0_usize; true_num_bins - 1];
+        let sums = PrefixSums::new(&sorted_data);
+        let gssd = calc_gssd(&sorted_data);
 
-    let mut max_gvf: f64 = 0.0;
+        PreparedData {
+            sorted_data,
+            unique_val_map,
+            sums,
+            gssd,
+        }
+    }
+
+    /// Returns the prepared data with the data point at the given (sorted) index removed, which needs no sorting
+    fn without(&self, idx: usize) -> Self {
+        let mut sorted_data: Vec<f64> = Vec::with_capacity(self.sorted_data.len() - 1);
+        sorted_data.extend_from_slice(&self.sorted_data[..idx]);
+        sorted_data.extend_from_slice(&self.sorted_data[idx + 1..]);
+        PreparedData::from_sorted(sorted_data)
+    }
+
+    /// Returns the prepared data points (sorted, ascending)
+    pub fn sorted_data(&self) -> &[f64] {
+        &self.sorted_data
+    }
+
+    /// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm given the desired number of bins, identical to those of get_jenks_breaks on the original data
+    ///
+    /// # Edge Cases
+    ///
+    /// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+    /// * Panics if num_bins is 0 (use PreparedData::try_jenks_breaks to get an error instead)
+    pub fn jenks_breaks(&self, num_bins: usize) -> Vec<f64> {
+        self.try_jenks_breaks(num_bins).unwrap()
+    }
 
+    /// Returns a vector of breaks generated through the Jenks Natural Breaks algorithm given the desired number of bins, or ClassifyError::ZeroBins if num_bins is 0
+    pub fn try_jenks_breaks(&self, num_bins: usize) -> Result<Vec<f64>, ClassifyError> {
+        validate_num_bins(num_bins)?;
+        let (breaks, _stats) = sampled_jenks_breaks(
+            num_bins,
+            &self.sorted_data,
+            &self.unique_val_map,
+            &self.sums,
+            &self.gssd,
+            &JenksConfig::new(),
+            BreakSampler::<StdRng>::Seeded(DEFAULT_SEED),
+        );
+        Ok(breaks)
+    }
+}
+
+/// The seed used for sampling breaks unless JenksConfig::seed is set
+const DEFAULT_SEED: u64 = 123456789;
+
+/// Returns the number of permutations to run on a dataset of num_vals data points unless JenksConfig::permutations is set, so that larger datasets are sampled fewer times
+fn default_permutations(num_vals: usize) -> usize {
     let c = 5000 * 2200 * 4;
-    let mut permutations = c / num_vals;
-    if permutations < 10 {
-        permutations = 10
+    (c / num_vals).clamp(10, 10000)
+}
+
+/// Represents the outcome of a run of the Jenks Natural Breaks algorithm: the goodness of variance fit (GVF) of the chosen breaks and the number of permutations that were evaluated
+#[derive(Debug, Clone, PartialEq)]
+pub struct JenksStats {
+    /// The goodness of variance fit of the chosen breaks, between 0 and 1
+    pub gvf: f64,
+    /// The number of sets of breaks evaluated, which is the number of possible sets when all of them were evaluated and may be fewer than configured when the patience ran out
+    pub permutations: usize,
+}
+
+/// Generates Jenks Natural Breaks and returns them along with the GVF of the chosen breaks and the number of permutations run
+fn jenks_breaks_and_stats<F: JenksFloat, R: Rng + ?Sized>(
+    num_bins: usize,
+    data: &[F],
+    config: &JenksConfig,
+    sampler: BreakSampler<R>,
+) -> Result<(Vec<F>, JenksStats), ClassifyError> {
+    if num_bins == 0 {
+        return Err(ClassifyError::ZeroBins);
     }
-    if permutations > 10000 {
-        permutations = 10000
+    let num_vals = data.len();
+    if num_vals == 0 {
+        return Err(ClassifyError::EmptyData);
     }
-    println!("permutations: {}", permutations);
 
-    let mut pseudo_rng = StdRng::seed_from_u64(123456789);
+    let sorted_data: Cow<[F]> = if config.assume_sorted {
+        debug_assert!(
+            data.windows(2).all(|pair| pair[0] <= pair[1]),
+            "data must be sorted when assume_sorted is set"
+        );
+        Cow::Borrowed(data)
+    } else {
+        let mut sorted_data: Vec<F> = data.to_vec();
+        sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Cow::Owned(sorted_data)
+    };
 
-    for _ in 0..permutations {
-        pick_rand_breaks(&mut unique_rand_breaks, &num_unique_vals, &mut pseudo_rng);
-        unique_to_normal_breaks(&unique_rand_breaks, &unique_val_map, &mut rand_breaks);
-        let new_gvf: f64 = calc_gvf(&rand_breaks, &sorted_data, &gssd);
+    let mut unique_val_map: Vec<UniqueVal<F>> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    if num_bins.min(unique_val_map.len()) == 1 {
+        return Ok((vec![], JenksStats::single_bin()));
+    }
+
+    let gssd = calc_gssd(&sorted_data);
+    let sums = PrefixSums::new(&sorted_data);
+
+    Ok(sampled_jenks_breaks(
+        num_bins,
+        &sorted_data,
+        &unique_val_map,
+        &sums,
+        &gssd,
+        config,
+        sampler,
+    ))
+}
+
+impl JenksStats {
+    /// Returns the stats of a run that produced a single bin, which evaluates no permutations
+    fn single_bin() -> JenksStats {
+        JenksStats {
+            gvf: 0.0,
+            permutations: 0,
+        }
+    }
+}
+
+/// Generates Jenks Natural Breaks from a dataset (sorted, ascending) whose unique value mapping, cumulative sums, and GSSD have already been calculated, and returns them along with the GVF of the chosen breaks and the number of permutations run
+fn sampled_jenks_breaks<F: JenksFloat, R: Rng + ?Sized>(
+    num_bins: usize,
+    sorted_data: &[F],
+    unique_val_map: &[UniqueVal<F>],
+    sums: &PrefixSums<F>,
+    gssd: &F,
+    config: &JenksConfig,
+    sampler: BreakSampler<R>,
+) -> (Vec<F>, JenksStats) {
+    let num_vals = sorted_data.len();
+    let num_unique_vals = unique_val_map.len();
+    let true_num_bins = core::cmp::min(num_unique_vals, num_bins);
+
+    if true_num_bins == 1 {
+        return (vec![], JenksStats::single_bin());
+    }
+
+    let num_breaks = true_num_bins - 1;
+    let permutations = match (config.permutations, config.patience) {
+        (Some(permutations), _) => permutations,
+        (None, Some(_)) => usize::MAX,
+        (None, None) => default_permutations(num_vals),
+    }
+    .max(1);
+    #[cfg(feature = "log")]
+    match config.patience {
+        Some(patience) => log::debug!("running Jenks permutations with a patience of {}", patience),
+        None => log::debug!("running {} Jenks permutations", permutations),
+    }
+
+    let (best_breaks, permutations_run) = sample_breaks(
+        num_breaks,
+        permutations,
+        config.patience,
+        unique_val_map,
+        sums,
+        gssd,
+        sampler,
+    );
+
+    let nat_breaks: Vec<F> = strictly_increasing_breaks(
+        best_breaks.iter().map(|idx| sorted_data[*idx]),
+        sorted_data[0],
+    );
+    #[cfg(feature = "log")]
+    log::debug!("chose Jenks breaks {:?}", to_vec_f64(&nat_breaks));
+
+    let gvf = sums.gvf(&best_breaks, gssd);
+    let stats = JenksStats {
+        gvf: gvf.to_f64().unwrap(),
+        permutations: permutations_run,
+    };
+
+    (nat_breaks, stats)
+}
+
+/// Evaluates up to the given number of random sets of breaks drawn by a BreakSampler (stopping early once a patience is exhausted) and returns the break indices with the best GVF along with the number of permutations run
+///
+/// If there are at most EXHAUSTIVE_LIMIT possible sets of breaks, all of them are evaluated instead, which finds the optimal breaks and ignores the number of permutations, patience, and sampler
+fn sample_breaks<F: JenksFloat, R: Rng + ?Sized>(
+    num_breaks: usize,
+    permutations: usize,
+    patience: Option<usize>,
+    unique_val_map: &[UniqueVal<F>],
+    sums: &PrefixSums<F>,
+    gssd: &F,
+    sampler: BreakSampler<R>,
+) -> (Vec<usize>, usize) {
+    if let Some(num_combinations) =
+        count_combinations(unique_val_map.len() - 1, num_breaks, EXHAUSTIVE_LIMIT)
+    {
+        return (
+            exhaustive_best_breaks(num_breaks, unique_val_map, sums, gssd),
+            num_combinations,
+        );
+    }
+
+    let num_unique_vals = unique_val_map.len();
+    match sampler {
+        BreakSampler::Rng(rng) => sample_best_breaks(
+            num_breaks,
+            permutations,
+            patience,
+            unique_val_map,
+            sums,
+            gssd,
+            |_, unique_rand_breaks| pick_rand_breaks(unique_rand_breaks, &num_unique_vals, rng),
+        ),
+        #[cfg(feature = "rayon")]
+        BreakSampler::Seeded(seed) if patience.is_none() => (
+            sample_best_breaks_parallel(num_breaks, permutations, unique_val_map, sums, gssd, seed),
+            permutations,
+        ),
+        BreakSampler::Seeded(seed) => sample_best_breaks(
+            num_breaks,
+            permutations,
+            patience,
+            unique_val_map,
+            sums,
+            gssd,
+            |i, unique_rand_breaks| {
+                let mut rng = StdRng::seed_from_u64(permutation_seed(seed, i));
+                pick_rand_breaks(unique_rand_breaks, &num_unique_vals, &mut rng)
+            },
+        ),
+    }
+}
+
+/// The largest number of possible sets of breaks for which Jenks evaluates every one instead of sampling, matching the largest default number of permutations
+const EXHAUSTIVE_LIMIT: usize = 10000;
+
+/// Returns the number of ways to choose k of n items, or None if it exceeds the limit
+fn count_combinations(n: usize, k: usize, limit: usize) -> Option<usize> {
+    if k > n {
+        return None;
+    }
+    let k = core::cmp::min(k, n - k);
+    let mut count: u128 = 1;
+    for i in 0..k {
+        count = count * (n - i) as u128 / (i + 1) as u128;
+        if count > limit as u128 {
+            return None;
+        }
+    }
+    Some(count as usize)
+}
+
+/// Evaluates every set of breaks placed on the first occurrences of unique values and returns the break indices with the best GVF, keeping the first (lowest) set in case of ties
+fn exhaustive_best_breaks<F: Float>(
+    num_breaks: usize,
+    unique_val_map: &[UniqueVal<F>],
+    sums: &PrefixSums<F>,
+    gssd: &F,
+) -> Vec<usize> {
+    let num_unique_vals = unique_val_map.len();
+    // Break positions are unique value indices 1..num_unique_vals, advanced like an odometer in lexicographic order
+    let mut unique_breaks: Vec<usize> = (1..=num_breaks).collect();
+    let mut breaks: Vec<usize> = vec![0_usize; num_breaks];
+    let mut best_breaks: Vec<usize> = vec![0_usize; num_breaks];
+
+    let mut max_gvf: F = F::zero();
+    loop {
+        unique_to_normal_breaks(&unique_breaks, unique_val_map, &mut breaks);
+        let new_gvf: F = sums.gvf(&breaks, gssd);
+        if new_gvf > max_gvf {
+            max_gvf = new_gvf;
+            best_breaks.copy_from_slice(&breaks);
+        }
+
+        // Find the last break that can still move right, then reset every break after it
+        let mut i = num_breaks;
+        while i > 0 && unique_breaks[i - 1] == num_unique_vals - num_breaks + i - 1 {
+            i -= 1;
+        }
+        if i == 0 {
+            break;
+        }
+        unique_breaks[i - 1] += 1;
+        for j in i..num_breaks {
+            unique_breaks[j] = unique_breaks[j - 1] + 1;
+        }
+    }
+
+    best_breaks
+}
+
+/// Represents where the random breaks evaluated by a Jenks run come from
+enum BreakSampler<'a, R: ?Sized> {
+    /// One random number generator per permutation, each seeded from the given seed and the permutation's index
+    Seeded(u64),
+    /// A caller-owned random number generator, which is always sampled serially
+    Rng(&'a mut R),
+}
+
+/// Evaluates up to the given number of random sets of breaks one after another and returns the break indices with the best GVF along with the number of permutations run, stopping early once `patience` consecutive permutations have not improved on the best GVF
+///
+/// `draw` fills in the unique value indices of the breaks for the permutation with the given index, and ties are won by the lowest index
+fn sample_best_breaks<F: Float>(
+    num_breaks: usize,
+    permutations: usize,
+    patience: Option<usize>,
+    unique_val_map: &[UniqueVal<F>],
+    sums: &PrefixSums<F>,
+    gssd: &F,
+    mut draw: impl FnMut(usize, &mut [usize]),
+) -> (Vec<usize>, usize) {
+    let mut rand_breaks: Vec<usize> = vec![0_usize; num_breaks];
+    let mut best_breaks: Vec<usize> = vec![0_usize; num_breaks];
+    let mut unique_rand_breaks: Vec<usize> = vec![0_usize; num_breaks];
+
+    let mut max_gvf: F = F::zero();
+    let mut permutations_run = 0;
+    let mut since_improvement = 0;
+    while permutations_run < permutations {
+        draw(permutations_run, &mut unique_rand_breaks);
+        permutations_run += 1;
+        unique_to_normal_breaks(&unique_rand_breaks, unique_val_map, &mut rand_breaks);
+        let new_gvf: F = sums.gvf(&rand_breaks, gssd);
         if new_gvf > max_gvf {
             max_gvf = new_gvf;
             best_breaks[..rand_breaks.len()].copy_from_slice(&rand_breaks[..]);
+            since_improvement = 0;
+        } else {
+            since_improvement += 1;
+            if patience.map_or(false, |patience| since_improvement >= patience) {
+                break;
+            }
         }
     }
 
-    let mut nat_breaks: Vec<f64> = vec![];
-    nat_breaks.resize(best_breaks.len(), 0.0);
-    for i in 0..best_breaks.len() {
-        nat_breaks[i] = sorted_data[best_breaks[i]];
+    (best_breaks, permutations_run)
+}
+
+/// Evaluates the given number of random sets of breaks across threads and returns the break indices with the best GVF
+///
+/// Each permutation draws from its own generator seeded from the base seed and its index, and ties are won by the lowest index, so the result does not depend on how the permutations are scheduled
+#[cfg(feature = "rayon")]
+fn sample_best_breaks_parallel<F: JenksFloat>(
+    num_breaks: usize,
+    permutations: usize,
+    unique_val_map: &[UniqueVal<F>],
+    sums: &PrefixSums<F>,
+    gssd: &F,
+    seed: u64,
+) -> Vec<usize> {
+    use rayon::prelude::*;
+
+    let num_unique_vals = unique_val_map.len();
+    let best = (0..permutations)
+        .into_par_iter()
+        .map(|i| {
+            let mut rng = StdRng::seed_from_u64(permutation_seed(seed, i));
+            let mut unique_rand_breaks: Vec<usize> = vec![0_usize; num_breaks];
+            let mut rand_breaks: Vec<usize> = vec![0_usize; num_breaks];
+            pick_rand_breaks(&mut unique_rand_breaks, &num_unique_vals, &mut rng);
+            unique_to_normal_breaks(&unique_rand_breaks, unique_val_map, &mut rand_breaks);
+            (sums.gvf(&rand_breaks, gssd), i, rand_breaks)
+        })
+        .reduce_with(|a, b| {
+            if b.0 > a.0 || (b.0 == a.0 && b.1 < a.1) {
+                b
+            } else {
+                a
+            }
+        });
+
+    match best {
+        Some((gvf, _, breaks)) if gvf > F::zero() => breaks,
+        _ => vec![0_usize; num_breaks],
     }
-    println!("Breaks: {:#?}", nat_breaks);
+}
 
-    nat_breaks
+/// Derives the seed of a single permutation's random number generator from the base seed and the permutation's index
+fn permutation_seed(seed: u64, idx: usize) -> u64 {
+    seed.wrapping_add((idx as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
 }
 
 /// Populates a vector with a set of breaks as unique random integers that are valid indices within the dataset given the number of data points and an RNG
 ///
+/// The breaks are drawn through a partial Fisher-Yates shuffle, so sampling takes exactly one random draw per break however close the number of breaks is to the number of data points
+///
 /// # Arguments
 ///
 /// * `breaks` - A mutable reference to an empty vector of breaks whose length is taken to be the desired number of breaks
 /// * `num_vals` - A reference to the number of data points
-/// * `rng` - A mutable reference to a seedable random number generator (RNG) from the "rand" crate
-pub fn pick_rand_breaks(breaks: &mut Vec<usize>, num_vals: &usize, rng: &mut StdRng) {
+/// * `rng` - A mutable reference to any random number generator (RNG) from the "rand" crate
+pub fn pick_rand_breaks<R: Rng + ?Sized>(breaks: &mut [usize], num_vals: &usize, rng: &mut R) {
     let num_breaks = breaks.len();
     if num_breaks > num_vals - 1 {
         return;
     }
 
-    let mut set = HashSet::new();
-    while set.len() < num_breaks {
-        set.insert(rng.gen_range(1..*num_vals));
-    }
-    let mut set_iter = set.iter();
-    for item in breaks.iter_mut().take(set_iter.len()) {
-        *item = *set_iter.next().unwrap();
+    // Partial Fisher-Yates shuffle of the candidate indices 1..num_vals, where position p holds index p + 1 until it is swapped
+    let num_candidates = num_vals - 1;
+    if num_breaks * num_breaks > num_candidates {
+        let mut candidates: Vec<usize> = (1..*num_vals).collect();
+        for (i, item) in breaks.iter_mut().enumerate() {
+            let j = rng.gen_range(i..num_candidates);
+            candidates.swap(i, j);
+            *item = candidates[i];
+        }
+    } else {
+        // With few breaks, only the positions that have been swapped are stored (as position, index pairs)
+        let mut swapped: Vec<(usize, usize)> = Vec::with_capacity(num_breaks);
+        let candidate_at = |swapped: &[(usize, usize)], pos: usize| -> usize {
+            match swapped.iter().find(|(swapped_pos, _)| *swapped_pos == pos) {
+                Some((_, idx)) => *idx,
+                None => pos + 1,
+            }
+        };
+        for (i, item) in breaks.iter_mut().enumerate() {
+            let j = rng.gen_range(i..num_candidates);
+            let at_i = candidate_at(&swapped, i);
+            let at_j = candidate_at(&swapped, j);
+            match swapped
+                .iter_mut()
+                .find(|(swapped_pos, _)| *swapped_pos == j)
+            {
+                Some(entry) => entry.1 = at_i,
+                None => swapped.push((j, at_i)),
+            }
+            *item = at_j;
+        }
     }
     breaks.sort_unstable();
 }
 
-/// Calculates goodness of variance fit (GVF) for a particular set of breaks on a dataset
+/// Holds cumulative weights, sums, and sums of squares of a dataset's values (sorted, ascending) so that the sum of squared deviations of any contiguous run of data points can be found in constant time
+#[derive(Debug, Clone)]
+pub struct PrefixSums<F> {
+    weights: Vec<F>,
+    sums: Vec<F>,
+    sq_sums: Vec<F>,
+}
+
+impl<F: Float> PrefixSums<F> {
+    /// Builds the cumulative sums for a dataset (sorted, ascending)
+    pub fn new(vals: &[F]) -> PrefixSums<F> {
+        let weights: Vec<F> = vec![F::one(); vals.len()];
+        PrefixSums::weighted(vals, &weights)
+    }
+
+    /// Builds the cumulative sums for a dataset (sorted, ascending) in which each data point carries a weight
+    pub fn weighted(vals: &[F], weights: &[F]) -> PrefixSums<F> {
+        // Values are centered on their weighted mean, and the running sums are compensated (Neumaier), to limit cancellation in sum_sq - sum^2 / count for data far from zero
+        let mut total_weight = F::zero();
+        let mut weighted_sum = F::zero();
+        for (item, weight) in vals.iter().zip(weights) {
+            total_weight = total_weight + *weight;
+            weighted_sum = weighted_sum + *weight * *item;
+        }
+        let offset = if total_weight > F::zero() {
+            weighted_sum / total_weight
+        } else {
+            F::zero()
+        };
+
+        let mut cum_weights: Vec<F> = vec![F::zero(); vals.len() + 1];
+        let mut sums: Vec<F> = vec![F::zero(); vals.len() + 1];
+        let mut sq_sums: Vec<F> = vec![F::zero(); vals.len() + 1];
+        let mut weight_acc = CompensatedSum::new();
+        let mut sum_acc = CompensatedSum::new();
+        let mut sq_sum_acc = CompensatedSum::new();
+        for (i, (item, weight)) in vals.iter().zip(weights).enumerate() {
+            let val = *item - offset;
+            cum_weights[i + 1] = weight_acc.add(*weight);
+            sums[i + 1] = sum_acc.add(*weight * val);
+            sq_sums[i + 1] = sq_sum_acc.add(*weight * val * val);
+        }
+
+        PrefixSums {
+            weights: cum_weights,
+            sums,
+            sq_sums,
+        }
+    }
+
+    /// Returns the (weighted) sum of squared deviations from their mean of the data points with indices lower..upper, using sum_sq - sum^2 / count
+    pub fn ssd(&self, lower: usize, upper: usize) -> F {
+        if upper <= lower {
+            return F::zero();
+        }
+        let count = self.weights[upper] - self.weights[lower];
+        if count <= F::zero() {
+            return F::zero();
+        }
+        let sum = self.sums[upper] - self.sums[lower];
+        let sq_sum = self.sq_sums[upper] - self.sq_sums[lower];
+        (sq_sum - sum * sum / count).max(F::zero())
+    }
+
+    /// Calculates goodness of variance fit (GVF) for a particular set of breaks in O(number of bins), matching calc_gvf up to floating point rounding
+    ///
+    /// # Arguments
+    ///
+    /// * `breaks` - A reference to a collection (usize) of break indices (sorted, ascending)
+    /// * `gssd` - A reference to the global sum of squared deviations (GSSD)
+    pub fn gvf(&self, breaks: &[usize], gssd: &F) -> F {
+        let num_vals = self.sums.len() - 1;
+        let mut tssd: F = F::zero();
+        let mut lower = 0;
+        for upper in breaks.iter().copied().chain(core::iter::once(num_vals)) {
+            tssd = tssd + self.ssd(lower, upper);
+            lower = upper;
+        }
+        F::one() - (tssd / *gssd)
+    }
+}
+
+/// Accumulates a running sum with Neumaier's compensated summation, which carries the rounding error of each addition forward
+struct CompensatedSum<F> {
+    sum: F,
+    compensation: F,
+}
+
+impl<F: Float> CompensatedSum<F> {
+    fn new() -> CompensatedSum<F> {
+        CompensatedSum {
+            sum: F::zero(),
+            compensation: F::zero(),
+        }
+    }
+
+    /// Adds a value and returns the compensated running sum
+    fn add(&mut self, val: F) -> F {
+        let total = self.sum + val;
+        if self.sum.abs() >= val.abs() {
+            self.compensation = self.compensation + ((self.sum - total) + val);
+        } else {
+            self.compensation = self.compensation + ((val - total) + self.sum);
+        }
+        self.sum = total;
+        self.sum + self.compensation
+    }
+}
+
+/// Calculates goodness of variance fit (GVF) for a particular set of breaks on a dataset, directly from the data points as a reference for PrefixSums::gvf
 ///
 /// # Arguments
 ///
-/// * `breaks` - A reference to a vector (usize) of break indices (sorted, ascending)
-/// * `vals` - A reference to a vector (f64) of data points (sorted, ascending)
+/// * `breaks` - A reference to a collection (usize) of break indices (sorted, ascending)
+/// * `vals` - A reference to a collection of floating point data points (sorted, ascending)
 /// * `gssd` - A reference to the global sum of squared deviations (GSSD)
-pub fn calc_gvf(breaks: &Vec<usize>, vals: &Vec<f64>, gssd: &f64) -> f64 {
+#[cfg(test)]
+pub fn calc_gvf<F: Float>(breaks: &[usize], vals: &[F], gssd: &F) -> F {
     let num_vals = vals.len();
     let num_bins = breaks.len() + 1;
-    let mut tssd: f64 = 0.0;
+    let mut tssd: F = F::zero();
     for i in 0..num_bins {
         let lower = if i == 0 { 0 } else { breaks[i - 1] };
         let upper = if i == num_bins - 1 {
@@ -172,43 +1645,56 @@ pub fn calc_gvf(breaks: &Vec<usize>, vals: &Vec<f64>, gssd: &f64) -> f64 {
             breaks[i]
         };
 
-        let mut mean: f64 = 0.0;
-        let mut ssd: f64 = 0.0;
-        for item in vals.iter().take(upper).skip(lower) {
-            mean += item;
-        }
-        mean /= (upper - lower) as f64;
-        for item in vals.iter().take(upper).skip(lower) {
-            ssd += (item - mean) * (item - mean)
-        }
-        tssd += ssd;
+        let ssd = calc_ssd(&vals[lower..upper]);
+        tssd = tssd + ssd;
     }
-    1.0 - (tssd / gssd)
+    F::one() - (tssd / *gssd)
 }
 
 /// Calculates global sum of squared deviations (GSSD) for a particular dataset
 ///
 /// # Arguments
 ///
-/// * `data` - A reference to a vector (f64) of data points (sorted, ascending)
-pub fn calc_gssd(data: &Vec<f64>) -> f64 {
-    let num_vals = data.len();
-    let mut mean = 0.0;
-    let mut max_val: f64 = data[0];
-    for item in data.iter().take(num_vals) {
-        let val = *item;
-        if val > max_val {
-            max_val = val
+/// * `data` - A reference to a collection of floating point data points (sorted, ascending)
+pub fn calc_gssd<F: Float>(data: &[F]) -> F {
+    calc_ssd(data)
+}
+
+/// Calculates the global sum of squared deviations (GSSD) of a dataset in which each data point carries a weight, using the weighted form of Welford's algorithm
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of floating point data points
+/// * `weights` - A reference to a collection of non-negative weights, one per data point
+pub fn calc_weighted_gssd<F: Float>(data: &[F], weights: &[F]) -> F {
+    let mut total_weight = F::zero();
+    let mut mean = F::zero();
+    let mut ssd = F::zero();
+    for (item, weight) in data.iter().zip(weights) {
+        if *weight <= F::zero() {
+            continue;
         }
-        mean += val;
+        total_weight = total_weight + *weight;
+        let delta = *item - mean;
+        mean = mean + delta * *weight / total_weight;
+        ssd = ssd + *weight * delta * (*item - mean);
     }
-    mean /= num_vals as f64;
+    ssd
+}
 
-    let mut gssd: f64 = 0.0;
-    for item in data.iter().take(num_vals) {
-        let val = *item;
-        gssd += (val - mean) * (val - mean);
-    }
+/// Calculates the sum of squared deviations from their mean of a collection of data points in a single pass, using Welford's online algorithm to avoid losing precision for large values with a small spread
+pub fn calc_ssd<F: Float>(data: &[F]) -> F {
+    calc_mean_and_ssd(data).1
+}
 
-    gssd
+/// Calculates the mean of a collection of data points along with the sum of squared deviations from it, using Welford's online algorithm (the mean of no data points is 0)
+pub fn calc_mean_and_ssd<F: Float>(data: &[F]) -> (F, F) {
+    let mut mean = F::zero();
+    let mut ssd = F::zero();
+    for (i, item) in data.iter().enumerate() {
+        let delta = *item - mean;
+        mean = mean + delta / F::from(i + 1).unwrap();
+        ssd = ssd + delta * (*item - mean);
+    }
+    (mean, ssd)
 }
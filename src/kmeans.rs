@@ -0,0 +1,405 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::ToPrimitive;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::error::ClassifyError;
+use crate::jenks::calc_ssd;
+use crate::utilities::{breaks_to_classification, create_unique_val_mapping, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+use crate::utilities::{Classification, UniqueVal};
+
+/// Returns a Classification object following the k-means algorithm given the desired number of bins, one-dimensional data, and a maximum number of iterations
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `max_iter` - The maximum number of iterations of Lloyd's algorithm to run
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_kmeans_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<usize> = vec![1, 2, 3, 10, 11, 12, 20, 21, 22];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_kmeans_classification(num_bins, &data, 100);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 6.5, count: 3},
+///     Bin{bin_start: 6.5, bin_end: 16.0, count: 3},
+///     Bin{bin_start: 16.0, bin_end: 22.0, count: 3}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_kmeans_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    max_iter: usize,
+) -> Classification {
+    let breaks: Vec<f64> = get_kmeans_breaks(num_bins, data, max_iter);
+    breaks_to_classification(&breaks, data)
+}
+
+/// Returns a Classification object following the k-means algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `max_iter` - The maximum number of iterations of Lloyd's algorithm to run
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_kmeans_classification, try_get_kmeans_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_kmeans_classification(3, &data, 100).unwrap() == get_kmeans_classification(3, &data, 100));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_kmeans_classification(3, &empty, 100), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_kmeans_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    max_iter: usize,
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_kmeans_classification(num_bins, data, max_iter))
+}
+
+/// Returns a Classification object following the k-means algorithm along with the final centroids and the number of iterations run
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `max_iter` - The maximum number of iterations of Lloyd's algorithm to run
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_kmeans_classification_with_stats;
+/// use classify::{Classification, KMeansStats};
+///
+/// let data: Vec<usize> = vec![1, 2, 3, 10, 11, 12, 20, 21, 22];
+///
+/// let (result, stats): (Classification, KMeansStats) = get_kmeans_classification_with_stats(3, &data, 100);
+///
+/// assert_eq!(result.len(), 3);
+/// assert_eq!(stats.centroids, vec![2.0, 11.0, 21.0]);
+/// assert!(stats.iterations <= 100);
+/// ```
+pub fn get_kmeans_classification_with_stats<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    max_iter: usize,
+) -> (Classification, KMeansStats) {
+    let stats = calc_kmeans(num_bins, data, &KMeansConfig::new().max_iter(max_iter));
+    let breaks = centroids_to_breaks(&stats.centroids);
+    (breaks_to_classification(&breaks, data), stats)
+}
+
+/// Returns a vector of breaks at the midpoints between adjacent centroids found through the k-means algorithm given the desired number of bins, a dataset, and a maximum number of iterations
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+/// * `max_iter` - The maximum number of iterations of Lloyd's algorithm to run
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_kmeans_breaks;
+///
+/// let data: Vec<i32> = vec![1, 2, 3, 10, 11, 12, 20, 21, 22];
+/// let num_bins = 3;
+///
+/// let result: Vec<f64> = get_kmeans_breaks(num_bins, &data, 100);
+///
+/// assert_eq!(result, vec![6.5, 16.0]);
+/// ```
+pub fn get_kmeans_breaks<T: ToPrimitive>(num_bins: usize, data: &[T], max_iter: usize) -> Vec<f64> {
+    let stats = calc_kmeans(num_bins, data, &KMeansConfig::new().max_iter(max_iter));
+    centroids_to_breaks(&stats.centroids)
+}
+
+/// Represents the outcome of running the k-means algorithm: the final centroids (sorted, ascending), the number of iterations that were run, and the within-class sum of squared deviations (SSD) of the resulting bins, which allows keeping the best of several seeded runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct KMeansStats {
+    /// The centroids of the bins after the last iteration (sorted, ascending)
+    pub centroids: Vec<f64>,
+    /// The number of iterations run, which is below the maximum when the centroids stopped moving first
+    pub iterations: usize,
+    /// The sum of the bins' squared deviations from their means, where lower is better
+    pub ssd: f64,
+}
+
+/// Represents how the centroids of the k-means algorithm are chosen before the first iteration
+#[derive(Debug, Clone, PartialEq)]
+pub enum KMeansInit {
+    /// Evenly spaced unique values of the data, which is deterministic but can settle in a poor local optimum for multimodal data
+    Quantile,
+    /// k-means++ seeding: the first centroid is a random data point, and each next one is a data point drawn with probability proportional to its squared distance from the nearest centroid chosen so far
+    PlusPlus,
+    /// The given starting centroids, whose number (once duplicates are removed) sets the number of bins
+    Custom(Vec<f64>),
+}
+
+/// Configures a run of the k-means algorithm
+///
+/// # Examples
+///
+/// ```
+/// use classify::{KMeansConfig, KMeansInit};
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 20.0, 21.0, 22.0];
+///
+/// let best = (0..5)
+///     .map(|seed| {
+///         KMeansConfig::new()
+///             .init(KMeansInit::PlusPlus)
+///             .seed(seed)
+///             .classify_with_stats(3, &data)
+///             .unwrap()
+///     })
+///     .min_by(|a, b| a.1.ssd.partial_cmp(&b.1.ssd).unwrap())
+///     .unwrap();
+///
+/// assert_eq!(best.1.centroids, vec![2.0, 11.0, 21.0]);
+/// assert_eq!(best.1.ssd, 6.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KMeansConfig {
+    max_iter: usize,
+    init: KMeansInit,
+    seed: u64,
+}
+
+impl Default for KMeansConfig {
+    fn default() -> Self {
+        KMeansConfig {
+            max_iter: 100,
+            init: KMeansInit::Quantile,
+            seed: DEFAULT_SEED,
+        }
+    }
+}
+
+impl KMeansConfig {
+    /// Creates a KMeansConfig with the default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of iterations of Lloyd's algorithm to run (defaults to 100)
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets how the starting centroids are chosen (defaults to KMeansInit::Quantile)
+    pub fn init(mut self, init: KMeansInit) -> Self {
+        self.init = init;
+        self
+    }
+
+    /// Sets the seed of the random number generator used by KMeansInit::PlusPlus
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns a Classification object following the k-means algorithm given the desired number of bins and one-dimensional data, or an error if one cannot be generated
+    ///
+    /// The number of bins is ignored for KMeansInit::Custom, which returns ClassifyError::ZeroBins if it holds no centroids and ClassifyError::NonFinite if one of them is NaN or infinite
+    pub fn classify<T: ToPrimitive>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+    ) -> Result<Classification, ClassifyError> {
+        Ok(self.classify_with_stats(num_bins, data)?.0)
+    }
+
+    /// Returns a Classification object following the k-means algorithm along with the final centroids, the number of iterations run, and the within-class SSD, or an error if one cannot be generated
+    pub fn classify_with_stats<T: ToPrimitive>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+    ) -> Result<(Classification, KMeansStats), ClassifyError> {
+        match &self.init {
+            KMeansInit::Custom(centroids) => {
+                validate_num_bins(centroids.len())?;
+                validate_data(centroids)?;
+            }
+            _ => validate_num_bins(num_bins)?,
+        }
+        validate_data(data)?;
+
+        let stats = calc_kmeans(num_bins, data, self);
+        let breaks = centroids_to_breaks(&stats.centroids);
+        Ok((breaks_to_classification(&breaks, data), stats))
+    }
+}
+
+/// The seed used by KMeansInit::PlusPlus unless KMeansConfig::seed is set
+const DEFAULT_SEED: u64 = 123456789;
+
+/// Runs Lloyd's algorithm on one-dimensional data, starting from the centroids chosen following the KMeansConfig
+fn calc_kmeans<T: ToPrimitive>(num_bins: usize, data: &[T], config: &KMeansConfig) -> KMeansStats {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    let num_unique_vals = unique_val_map.len();
+    let true_num_bins = core::cmp::max(core::cmp::min(num_unique_vals, num_bins), 1);
+
+    let mut centroids: Vec<f64> = match &config.init {
+        KMeansInit::Quantile => (0..true_num_bins)
+            .map(|i| unique_val_map[(2 * i + 1) * num_unique_vals / (2 * true_num_bins)].val)
+            .collect(),
+        KMeansInit::PlusPlus => plus_plus_centroids(
+            true_num_bins,
+            &sorted_data,
+            &mut StdRng::seed_from_u64(config.seed),
+        ),
+        KMeansInit::Custom(centroids) => centroids.to_vec(),
+    };
+    centroids.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    centroids.dedup();
+    let true_num_bins = centroids.len();
+
+    let mut iterations = 0;
+    while iterations < config.max_iter {
+        iterations += 1;
+
+        let mut sums: Vec<f64> = vec![0.0; true_num_bins];
+        let mut counts: Vec<usize> = vec![0; true_num_bins];
+        for (cluster, members) in clusters(&sorted_data, &centroids).iter().enumerate() {
+            sums[cluster] = members.iter().sum();
+            counts[cluster] = members.len();
+        }
+
+        let mut new_centroids: Vec<f64> = centroids.to_vec();
+        for i in 0..true_num_bins {
+            if counts[i] > 0 {
+                new_centroids[i] = sums[i] / counts[i] as f64;
+            }
+        }
+
+        if new_centroids == centroids {
+            break;
+        }
+        centroids = new_centroids;
+    }
+
+    let ssd = clusters(&sorted_data, &centroids)
+        .iter()
+        .map(|members| calc_ssd(members))
+        .sum();
+
+    KMeansStats {
+        centroids,
+        iterations,
+        ssd,
+    }
+}
+
+/// Splits data (sorted, ascending) into the runs of values closest to each centroid (sorted, ascending)
+///
+/// Each value belongs to the centroid it is closest to, so clusters are contiguous runs of the sorted data
+fn clusters<'a>(sorted_data: &'a [f64], centroids: &[f64]) -> Vec<&'a [f64]> {
+    let mut clusters: Vec<&[f64]> = vec![];
+    let mut lower = 0;
+    for i in 0..centroids.len() {
+        let upper = if i == centroids.len() - 1 {
+            sorted_data.len()
+        } else {
+            let midpoint = (centroids[i] + centroids[i + 1]) / 2.0;
+            lower + sorted_data[lower..].partition_point(|val| *val < midpoint)
+        };
+        clusters.push(&sorted_data[lower..upper]);
+        lower = upper;
+    }
+    clusters
+}
+
+/// Chooses starting centroids through k-means++ seeding, drawing each next centroid from the data points with probability proportional to its squared distance from the nearest centroid chosen so far
+fn plus_plus_centroids<R: Rng + ?Sized>(
+    num_bins: usize,
+    sorted_data: &[f64],
+    rng: &mut R,
+) -> Vec<f64> {
+    let mut centroids: Vec<f64> = vec![sorted_data[rng.gen_range(0..sorted_data.len())]];
+    let mut distances: Vec<f64> = sorted_data
+        .iter()
+        .map(|val| (val - centroids[0]) * (val - centroids[0]))
+        .collect();
+
+    while centroids.len() < num_bins {
+        let total: f64 = distances.iter().sum();
+        if total <= 0.0 {
+            break;
+        }
+        let target = rng.gen::<f64>() * total;
+        let mut cumulative = 0.0;
+        let mut chosen = sorted_data.len() - 1;
+        for (i, distance) in distances.iter().enumerate() {
+            cumulative += distance;
+            if cumulative > target {
+                chosen = i;
+                break;
+            }
+        }
+
+        let centroid = sorted_data[chosen];
+        centroids.push(centroid);
+        for (distance, val) in distances.iter_mut().zip(sorted_data) {
+            *distance = distance.min((val - centroid) * (val - centroid));
+        }
+    }
+
+    centroids
+}
+/// Converts a set of centroids (sorted, ascending) to breaks at the midpoints between adjacent centroids
+fn centroids_to_breaks(centroids: &[f64]) -> Vec<f64> {
+    let mut breaks: Vec<f64> = vec![];
+    for i in 1..centroids.len() {
+        breaks.push((centroids[i - 1] + centroids[i]) / 2.0);
+    }
+    breaks
+}
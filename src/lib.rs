@@ -1,26 +1,198 @@
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
+
 mod jenks;
-pub use jenks::{get_jenks_breaks, get_jenks_classification};
+pub use jenks::get_jenks_classification_iter;
+pub use jenks::{bootstrap_break_stability, gvf_significance, jackknife_break_influence};
+pub use jenks::{get_jenks_breaks, get_jenks_classification, get_jenks_classification_with_gvf};
+pub use jenks::{get_jenks_breaks_float, get_jenks_classification_float};
+pub use jenks::{get_trimmed_jenks_classification, try_get_trimmed_jenks_classification};
+pub use jenks::{
+    get_weighted_jenks_breaks, get_weighted_jenks_classification,
+    try_get_weighted_jenks_classification,
+};
+pub use jenks::{try_get_jenks_breaks, try_get_jenks_classification};
+pub use jenks::{JenksConfig, JenksFloat, JenksStats, PreparedData};
 
 mod quantile;
+pub use quantile::get_quantile_classification_streaming;
+pub use quantile::try_get_quantile_classification;
+pub use quantile::{
+    get_approximate_quantile_classification, try_get_approximate_quantile_classification,
+};
 pub use quantile::{get_quantile_breaks, get_quantile_classification};
 
 mod head_tail;
+pub use head_tail::try_get_head_tail_classification;
 pub use head_tail::{get_head_tail_breaks, get_head_tail_classification};
 
 mod equal_interval;
+pub use equal_interval::try_get_equal_interval_classification;
 pub use equal_interval::{get_equal_interval_breaks, get_equal_interval_classification};
 
 mod standard_deviation;
-pub use standard_deviation::{get_st_dev_breaks, get_st_dev_classification};
+pub use standard_deviation::try_get_st_dev_classification;
+pub use standard_deviation::StDevStats;
+pub use standard_deviation::{
+    get_st_dev_breaks, get_st_dev_classification, get_st_dev_classification_with_stats,
+};
 
 mod hinge;
-pub use hinge::{get_hinge_breaks, get_hinge_classification};
+pub use hinge::detect_outliers_iqr;
+pub use hinge::BoxplotStats;
+pub use hinge::{
+    get_boxplot_classification, get_boxplot_classification_with_stats, get_hinge_breaks,
+    get_hinge_classification,
+};
+pub use hinge::{try_get_boxplot_classification, try_get_hinge_classification};
+
+mod fisher_jenks;
+pub use fisher_jenks::try_get_fisher_jenks_classification;
+pub use fisher_jenks::{
+    get_fisher_jenks_breaks, get_fisher_jenks_classification, suggest_num_bins,
+    suggest_num_bins_elbow,
+};
+
+mod maximum_breaks;
+pub use maximum_breaks::try_get_maximum_breaks_classification;
+pub use maximum_breaks::{
+    find_natural_gaps, get_maximum_breaks, get_maximum_breaks_classification,
+};
+
+mod pretty;
+pub use pretty::try_get_pretty_classification;
+pub use pretty::{get_pretty_breaks, get_pretty_classification};
+
+mod kmeans;
+pub use kmeans::try_get_kmeans_classification;
+pub use kmeans::{
+    get_kmeans_breaks, get_kmeans_classification, get_kmeans_classification_with_stats,
+    KMeansConfig, KMeansInit, KMeansStats,
+};
+
+mod percentile;
+pub use percentile::{
+    empirical_cdf, get_percentile_breaks, get_percentile_classification, percentile_of,
+    PercentileInterpolation,
+};
+pub use percentile::{get_tailed_quantile_classification, try_get_tailed_quantile_classification};
+
+mod logarithmic;
+pub use logarithmic::{get_logarithmic_breaks, get_logarithmic_classification};
+
+mod unique_value;
+pub use unique_value::try_get_unique_value_classification;
+pub use unique_value::{get_top_n_unique_classification, get_unique_value_classification};
+
+mod ckmeans;
+pub use ckmeans::try_get_ckmeans_classification;
+pub use ckmeans::{
+    get_ckmeans_breaks, get_ckmeans_classification, get_ckmeans_classification_with_ssd,
+};
+
+mod defined_interval;
+pub use defined_interval::{get_defined_interval_breaks, get_defined_interval_classification};
+
+mod geometric_interval;
+pub use geometric_interval::try_get_geometric_interval_classification;
+pub use geometric_interval::{
+    get_geometric_interval_breaks, get_geometric_interval_classification,
+};
+
+mod arithmetic_interval;
+pub use arithmetic_interval::try_get_arithmetic_interval_classification;
+pub use arithmetic_interval::{
+    get_arithmetic_interval_breaks, get_arithmetic_interval_classification,
+};
+
+mod harmonic_interval;
+pub use harmonic_interval::{get_harmonic_interval_breaks, get_harmonic_interval_classification};
+
+mod sqrt_interval;
+pub use sqrt_interval::{get_sqrt_interval_breaks, get_sqrt_interval_classification};
+
+mod max_entropy;
+pub use max_entropy::try_get_max_entropy_classification;
+pub use max_entropy::{
+    get_max_entropy_breaks, get_max_entropy_classification,
+    get_max_entropy_classification_with_entropy,
+};
+
+mod nested_means;
+pub use nested_means::try_get_nested_means_classification;
+pub use nested_means::{get_nested_means_breaks, get_nested_means_classification};
+
+mod diverging;
+pub use diverging::try_get_diverging_classification;
+pub use diverging::{get_diverging_breaks, get_diverging_classification, DivergingSpacing};
+
+mod metrics;
+pub use metrics::{
+    calinski_harabasz_1d, classification_agreement, davies_bouldin_1d,
+    goodness_of_absolute_deviation_fit, goodness_of_variance_fit, information_loss,
+    per_class_absolute_deviation, per_class_variance, silhouette_score_1d, tabular_accuracy_index,
+};
+
+mod summary;
+pub use summary::{suggest_bin_count, BinCountRule, DataSummary};
+
+mod method;
+pub use method::{classify_batch, recommend_method};
+pub use method::{ClassificationMethod, ClassificationResult, Warning};
+
+mod bivariate;
+pub use bivariate::{get_bivariate_classification, BivariateClassification};
+
+#[cfg(feature = "std")]
+mod export;
+#[cfg(feature = "serde")]
+pub use export::classification_to_json;
+#[cfg(feature = "std")]
+pub use export::write_classification_csv;
+
+#[cfg(feature = "ndarray")]
+mod array;
+#[cfg(feature = "ndarray")]
+pub use array::{
+    get_arithmetic_interval_classification_ndarray, get_boxplot_classification_ndarray,
+    get_ckmeans_classification_ndarray, get_defined_interval_classification_ndarray,
+    get_equal_interval_classification_ndarray, get_fisher_jenks_classification_ndarray,
+    get_geometric_interval_classification_ndarray, get_head_tail_classification_ndarray,
+    get_hinge_classification_ndarray, get_jenks_classification_ndarray,
+    get_kmeans_classification_ndarray, get_logarithmic_classification_ndarray,
+    get_manual_classification_ndarray, get_maximum_breaks_classification_ndarray,
+    get_percentile_classification_ndarray, get_pretty_classification_ndarray,
+    get_quantile_classification_ndarray, get_st_dev_classification_ndarray,
+    get_unique_value_classification_ndarray,
+};
+
+#[cfg(feature = "plotters")]
+mod plot;
+#[cfg(feature = "plotters")]
+pub use plot::render_histogram;
+
+#[cfg(feature = "colors")]
+mod colors;
+#[cfg(feature = "colors")]
+pub use colors::assign_colors;
+
+mod manual;
+pub use manual::{get_manual_classification, parse_breaks};
+
+mod error;
+pub use error::ClassifyError;
+
+mod classification;
+pub use classification::{ClassificationExt, LabelOptions, SnapMode};
+
+mod tdigest;
 
 mod utilities;
-pub use utilities::{breaks_to_classification, classify_val};
-pub use utilities::{Bin, Classification};
+pub use utilities::{breaks_to_classification, breaks_to_classification_float, classify_val};
+pub use utilities::{Bin, Classification, NanPolicy};
 
-#[cfg(target_family = "wasm")]
+#[cfg(all(target_family = "wasm", feature = "js"))]
 mod wasm;
 
 #[cfg(test)]
@@ -60,7 +232,7 @@ mod tests {
 
         utilities::create_unique_val_mapping(&mut unique_val_map, &data);
 
-        let expected = vec![
+        let expected = [
             UniqueVal {
                 val: 2.0,
                 first: 0,
@@ -123,7 +295,7 @@ mod tests {
 
         jenks::pick_rand_breaks(&mut breaks, &num_vals, &mut rng);
 
-        assert_eq!(breaks, vec![3, 7], "pick_rand_breaks not working!");
+        assert_eq!(breaks, vec![4, 7], "pick_rand_breaks not working!");
     }
 
     #[test]
@@ -150,4 +322,2494 @@ mod tests {
 
         assert_eq!(standard_deviation::calc_st_dev(&data), 1.0801234497346435);
     }
+
+    #[test]
+    fn test_quantile_ties() {
+        let data: Vec<f64> = vec![1.0, 2.0, 2.0, 2.0, 2.0, 3.0];
+
+        assert_eq!(get_quantile_breaks(3, &data), vec![2.0]);
+
+        let data: Vec<f64> = vec![1.0, 1.0, 1.0, 1.0, 2.0, 3.0];
+
+        assert_eq!(get_quantile_breaks(3, &data), vec![1.5]);
+    }
+
+    #[test]
+    fn test_st_dev_breaks_within_range() {
+        let data: Vec<f64> = vec![0.0, 1.0, 2.0];
+
+        // A bin size of one standard deviation places breaks exactly on the minimum and maximum
+        assert_eq!(get_st_dev_breaks(1.0, &data), vec![1.0]);
+    }
+
+    #[test]
+    fn test_head_tail_uniform_data() {
+        let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        assert_eq!(get_head_tail_breaks(&data), vec![5.5]);
+
+        let data: Vec<f64> = vec![3.0; 10];
+        assert_eq!(get_head_tail_breaks(&data), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_geometric_interval_non_positive_data() {
+        let data: Vec<f64> = vec![-1.0, 0.0, 2.0, 6.0, 14.0];
+
+        // Data is shifted to [1.0, 16.0] so the breaks are [2.0, 4.0, 8.0] shifted back by 2.0
+        assert_eq!(get_geometric_interval_breaks(4, &data), vec![0.0, 2.0, 6.0]);
+    }
+
+    #[test]
+    fn test_fisher_jenks_unique_breaks() {
+        let mut unique_val_map: Vec<UniqueVal> = vec![];
+        let data: Vec<f64> = vec![1.0, 2.0, 2.0, 4.0, 5.0, 7.0, 7.0, 7.0, 8.0, 12.0];
+        utilities::create_unique_val_mapping(&mut unique_val_map, &data);
+        let gssd = jenks::calc_gssd(&data);
+
+        // Exhaustively search every placement of two breaks for the best GVF
+        let mut best_gvf = 0.0;
+        let mut normal_breaks: Vec<usize> = vec![];
+        for i in 1..unique_val_map.len() {
+            for j in (i + 1)..unique_val_map.len() {
+                utilities::unique_to_normal_breaks(&[i, j], &unique_val_map, &mut normal_breaks);
+                let gvf = jenks::calc_gvf(&normal_breaks, &data, &gssd);
+                if gvf > best_gvf {
+                    best_gvf = gvf;
+                }
+            }
+        }
+
+        let breaks = fisher_jenks::fisher_jenks_unique_breaks(3, &unique_val_map);
+        utilities::unique_to_normal_breaks(&breaks, &unique_val_map, &mut normal_breaks);
+
+        assert_eq!(jenks::calc_gvf(&normal_breaks, &data, &gssd), best_gvf);
+    }
+
+    #[test]
+    fn test_fisher_jenks_beats_jenks() {
+        let mut rng = StdRng::seed_from_u64(987654321);
+        let mut data: Vec<f64> = (0..500).map(|_| rng.gen_range(0.0..100.0)).collect();
+        let gssd = jenks::calc_gssd(&data);
+        let jenks_breaks = get_jenks_breaks(6, &data);
+        let fisher_breaks = get_fisher_jenks_breaks(6, &data);
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let to_indices = |breaks: &[f64]| -> Vec<usize> {
+            breaks
+                .iter()
+                .map(|brk| data.iter().position(|val| val == brk).unwrap())
+                .collect()
+        };
+        let jenks_gvf = jenks::calc_gvf(&to_indices(&jenks_breaks), &data, &gssd);
+        let fisher_gvf = jenks::calc_gvf(&to_indices(&fisher_breaks), &data, &gssd);
+
+        assert!(fisher_gvf >= jenks_gvf - 1e-12);
+    }
+
+    #[test]
+    fn test_equal_interval_constant_data() {
+        let data: Vec<f64> = vec![4.0, 4.0, 4.0, 4.0];
+
+        assert_eq!(get_equal_interval_breaks(3, &data), Vec::<f64>::new());
+        assert_eq!(get_equal_interval_classification(3, &data).len(), 1);
+    }
+
+    #[test]
+    fn test_maximum_breaks_few_unique_values() {
+        let data: Vec<f64> = vec![1.0, 1.0, 5.0, 5.0];
+
+        assert_eq!(get_maximum_breaks(4, &data), vec![3.0]);
+    }
+
+    #[test]
+    fn test_pretty_decimal_breaks() {
+        let data: Vec<f64> = vec![0.12, 0.47, 0.93];
+        let result = get_pretty_classification(3, &data);
+
+        assert_eq!(get_pretty_breaks(3, &data), vec![0.2, 0.4, 0.6, 0.8]);
+        assert_eq!(result[0].bin_start, 0.0);
+        assert_eq!(result[result.len() - 1].bin_end, 1.0);
+    }
+
+    #[test]
+    fn test_manual_classification_duplicate_breaks() {
+        let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+
+        assert_eq!(
+            get_manual_classification(&[2.0, 2.0], &data),
+            Err(ClassifyError::UnsortedBreaks)
+        );
+        assert_eq!(
+            get_manual_classification(&[2.0, f64::NAN], &data),
+            Err(ClassifyError::UnsortedBreaks)
+        );
+    }
+
+    #[test]
+    fn test_boxplot_outliers() {
+        let data: Vec<f64> = vec![
+            0.0, 1.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 20.0, 25.0,
+        ];
+        let counts: Vec<u64> = get_boxplot_classification(&data)
+            .iter()
+            .map(|bin| bin.count)
+            .collect();
+
+        assert_eq!(counts, vec![2, 1, 2, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_kmeans_iterations() {
+        let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 40.0];
+
+        let (_, stats) = get_kmeans_classification_with_stats(2, &data, 0);
+        assert_eq!(stats.centroids, vec![3.0, 7.0]);
+        assert_eq!(stats.iterations, 0);
+
+        let (_, stats) = get_kmeans_classification_with_stats(2, &data, 100);
+        assert_eq!(stats.centroids, vec![4.0, 40.0]);
+        assert!(stats.iterations < 100);
+    }
+
+    #[test]
+    fn test_percentile_validation() {
+        let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+        let interpolation = PercentileInterpolation::Linear;
+
+        assert_eq!(
+            get_percentile_breaks(&[50.0, 10.0], &data, interpolation),
+            Err(ClassifyError::UnsortedBreaks)
+        );
+        assert_eq!(
+            get_percentile_breaks(&[50.0, 150.0], &data, interpolation),
+            Err(ClassifyError::PercentileOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_unique_value_many_values() {
+        let data: Vec<usize> = (0..5000).map(|i| i % 2500).collect();
+
+        let result = get_unique_value_classification(&data, None);
+        assert_eq!(result.len(), 2500);
+
+        let result = get_unique_value_classification(&data, Some(10));
+        let total: u64 = result.iter().map(|bin| bin.count).sum();
+        assert_eq!(result.len(), 10);
+        assert_eq!(total, 5000);
+        assert_eq!(result[9].bin_start, 9.0);
+        assert_eq!(result[9].bin_end, 2499.0);
+    }
+
+    #[test]
+    fn test_ckmeans_beats_jenks() {
+        let mut rng = StdRng::seed_from_u64(192837465);
+        let mut data: Vec<f64> = (0..300)
+            .map(|_| rng.gen_range(0.0..50.0_f64).powi(2))
+            .collect();
+        let (_, ckmeans_ssd) = get_ckmeans_classification_with_ssd(5, &data);
+        let jenks_breaks = get_jenks_breaks(5, &data);
+
+        assert_eq!(
+            get_ckmeans_breaks(5, &data),
+            get_fisher_jenks_breaks(5, &data)
+        );
+
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let jenks_indices: Vec<usize> = jenks_breaks
+            .iter()
+            .map(|brk| data.iter().position(|val| val == brk).unwrap())
+            .collect();
+        let gssd = jenks::calc_gssd(&data);
+        let jenks_ssd = (1.0 - jenks::calc_gvf(&jenks_indices, &data, &gssd)) * gssd;
+
+        assert!(ckmeans_ssd <= jenks_ssd + 1e-6);
+    }
+
+    #[test]
+    fn test_tabular_accuracy_index() {
+        let data: Vec<f64> = vec![1.0, 2.0, 6.0, 10.0, 11.0, 21.0];
+        let single: Classification = vec![Bin {
+            bin_start: 1.0,
+            bin_end: 21.0,
+            count: 6,
+        }];
+        let split = get_manual_classification(&[10.0], &data).unwrap();
+
+        assert_eq!(tabular_accuracy_index(&single, &data), 0.0);
+        // Deviations from the class medians 2 and 11: 1 + 0 + 4 + 1 + 0 + 10 = 16, from the overall median 8: 7 + 6 + 2 + 2 + 3 + 13 = 33
+        assert_eq!(tabular_accuracy_index(&split, &data), 1.0 - 16.0 / 33.0);
+    }
+
+    #[test]
+    fn test_goodness_of_absolute_deviation_fit() {
+        let data: Vec<f64> = vec![1.0, 2.0, 9.0, 10.0, 11.0, 30.0];
+        let split = get_manual_classification(&[5.0], &data).unwrap();
+
+        // Class deviations about medians 1.5 and 10.5: 0.5 + 0.5 + 1.5 + 0.5 + 0.5 + 19.5 = 23
+        // Overall deviations about median 9.5: 8.5 + 7.5 + 0.5 + 0.5 + 1.5 + 20.5 = 39
+        assert_eq!(
+            goodness_of_absolute_deviation_fit(&split, &data),
+            1.0 - 23.0 / 39.0
+        );
+        assert_eq!(metrics::calc_median(&[3.0, 1.0, 2.0]), 2.0);
+        assert_eq!(metrics::calc_median(&[4.0, 1.0, 2.0, 3.0]), 2.5);
+    }
+
+    #[test]
+    fn test_classification_agreement() {
+        let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let a = get_manual_classification(&[3.0], &data).unwrap();
+        let b = get_manual_classification(&[4.0], &data).unwrap();
+        let c = get_manual_classification(&[2.0, 5.0], &data).unwrap();
+
+        assert_eq!(classification_agreement(&a, &a, &data), 1.0);
+        assert_eq!(classification_agreement(&a, &b, &data), 5.0 / 6.0);
+        // Values 5 and 6 land in c's third bin, which a lacks, so only 4 points are compared (1, 3, 4 agree)
+        assert_eq!(classification_agreement(&a, &c, &data), 0.75);
+        assert_eq!(classification_agreement(&c, &a, &data), 0.75);
+    }
+
+    #[test]
+    fn test_suggest_num_bins() {
+        let data: Vec<f64> = vec![1.0, 1.2, 0.8, 10.0, 10.3, 9.7, 20.0, 20.1, 19.9, 50.0];
+
+        // One bin has a GVF of 0, so a target of 0 needs no splitting
+        assert_eq!(suggest_num_bins(&data, 0.0, 10), 1);
+        let suggested = suggest_num_bins(&data, 0.99, 10);
+        let (_, gvf) = get_jenks_classification_with_gvf(suggested, &data);
+        assert!(gvf >= 0.99);
+        assert!(suggest_num_bins(&data, 0.99, 10) <= suggest_num_bins(&data, 0.999, 10));
+        assert_eq!(suggest_num_bins(&data, 1.0, 3), 3);
+        assert_eq!(suggest_num_bins(&[5.0, 5.0, 5.0], 0.9, 4), 1);
+    }
+
+    #[test]
+    fn test_jenks_empty_data() {
+        let empty: Vec<f64> = vec![];
+        assert_eq!(
+            try_get_jenks_breaks(3, &empty),
+            Err(ClassifyError::EmptyData)
+        );
+        assert_eq!(
+            try_get_jenks_classification(3, &empty),
+            Err(ClassifyError::EmptyData)
+        );
+        assert!(std::panic::catch_unwind(|| get_jenks_breaks(3, &empty)).is_err());
+    }
+
+    #[test]
+    fn test_jenks_nan_policy() {
+        let data: Vec<f64> = vec![1.0, f64::NAN, 2.0, 4.0, 5.0, f64::INFINITY, 7.0, 8.0];
+
+        assert_eq!(
+            try_get_jenks_breaks(3, &data),
+            Err(ClassifyError::NonFinite)
+        );
+        assert_eq!(
+            JenksConfig::new()
+                .nan_policy(NanPolicy::Error)
+                .classify(3, &data),
+            Err(ClassifyError::NonFinite)
+        );
+
+        let config = JenksConfig::new().nan_policy(NanPolicy::Drop);
+        assert_eq!(config.breaks(3, &data), Ok(vec![4.0, 7.0]));
+        let result = config.classify(3, &data).unwrap();
+        assert_eq!(result.iter().map(|bin| bin.count).sum::<u64>(), 6);
+        assert_eq!(result[2].bin_end, 8.0);
+
+        let all_nan: Vec<f64> = vec![f64::NAN, f64::NEG_INFINITY];
+        assert_eq!(config.classify(3, &all_nan), Err(ClassifyError::EmptyData));
+    }
+
+    #[test]
+    fn test_jenks_zero_and_one_bins() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+
+        assert_eq!(try_get_jenks_breaks(0, &data), Err(ClassifyError::ZeroBins));
+        assert_eq!(
+            try_get_jenks_classification(0, &data),
+            Err(ClassifyError::ZeroBins)
+        );
+
+        assert_eq!(get_jenks_breaks(1, &data), Vec::<f64>::new());
+        let expected: Classification = vec![Bin {
+            bin_start: 1.0,
+            bin_end: 8.0,
+            count: 6,
+        }];
+        assert_eq!(get_jenks_classification(1, &data), expected);
+        assert_eq!(get_jenks_classification_with_gvf(1, &data).1, 0.0);
+    }
+
+    #[test]
+    fn test_constant_data() {
+        let data: Vec<f64> = vec![5.0; 100];
+        let expected: Classification = vec![Bin {
+            bin_start: 5.0,
+            bin_end: 5.0,
+            count: 100,
+        }];
+
+        assert_eq!(get_jenks_classification(3, &data), expected);
+        assert_eq!(get_jenks_classification_with_gvf(3, &data).1, 0.0);
+        assert_eq!(get_fisher_jenks_classification(3, &data), expected);
+        assert_eq!(get_ckmeans_classification(3, &data), expected);
+        assert_eq!(get_kmeans_classification(3, &data, 100), expected);
+        assert_eq!(get_maximum_breaks_classification(3, &data), expected);
+        assert_eq!(get_unique_value_classification(&data, None), expected);
+        assert_eq!(get_quantile_classification(3, &data), expected);
+        assert_eq!(get_equal_interval_classification(3, &data), expected);
+        assert_eq!(get_head_tail_classification(&data), expected);
+        assert_eq!(breaks_to_classification(&[], &data), expected);
+    }
+
+    #[test]
+    fn test_breaks_to_classification_duplicates() {
+        let mut data: Vec<f64> = vec![1.0, 2.0];
+        data.extend(vec![3.0; 50]);
+        data.extend(vec![4.0, 5.0]);
+        data.extend(vec![5.0; 20]);
+
+        let result = breaks_to_classification(&[3.0], &data);
+        assert_eq!(result[0].count, 2);
+        assert_eq!(result[1].count, 72);
+        assert_eq!(
+            result.iter().map(|bin| bin.count).sum::<u64>(),
+            data.len() as u64
+        );
+
+        let result = breaks_to_classification(&[2.0, 3.0, 4.0], &data);
+        let counts: Vec<u64> = result.iter().map(|bin| bin.count).collect();
+        assert_eq!(counts, vec![1, 1, 50, 22]);
+    }
+
+    #[test]
+    fn test_jenks_assume_sorted() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+        let config = JenksConfig::new().assume_sorted(true);
+
+        assert_eq!(config.breaks(3, &data), Ok(get_jenks_breaks(3, &data)));
+        assert_eq!(
+            config.classify(3, &data),
+            Ok(get_jenks_classification(3, &data))
+        );
+
+        let unsorted: Vec<f64> = vec![8.0, 1.0, 5.0];
+        if cfg!(debug_assertions) {
+            assert!(std::panic::catch_unwind(|| config.breaks(2, &unsorted)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_classification_ext_classify() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 8.0];
+        let class = get_manual_classification(&[2.0, 4.0, 7.0], &data).unwrap();
+
+        for val in [0.0, 1.0, 1.5, 2.0, 3.9, 4.0, 6.0, 7.0, 8.0, 8.5] {
+            assert_eq!(class.classify(val), classify_val(val, &class));
+        }
+        assert_eq!(class.classify(8.0), Some(3));
+        assert_eq!(class.classify(f64::NAN), None);
+
+        assert_eq!(class.classify_clamped(f64::NEG_INFINITY), Some(0));
+        assert_eq!(class.classify_clamped(4.5), Some(2));
+        assert_eq!(class.classify_clamped(100.0), Some(3));
+        assert_eq!(class.classify_clamped(f64::NAN), None);
+
+        let empty: Classification = vec![];
+        assert_eq!(empty.classify(1.0), None);
+        assert_eq!(empty.classify_clamped(1.0), None);
+    }
+
+    #[test]
+    fn test_classification_ext_breaks_round_trip() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let data: Vec<f64> = (0..200).map(|_| rng.gen_range(0.0..100.0)).collect();
+
+        let classifications: Vec<Classification> = vec![
+            get_jenks_classification(5, &data),
+            get_quantile_classification(4, &data),
+            get_equal_interval_classification(6, &data),
+            get_ckmeans_classification(3, &data),
+            get_head_tail_classification(&data),
+        ];
+        for class in classifications {
+            assert_eq!(class.breaks().len(), class.len() - 1);
+            assert_eq!(Classification::from_breaks(&class.breaks(), &data), class);
+        }
+
+        let empty: Classification = vec![];
+        assert_eq!(empty.breaks(), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_classification_iteration() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+        let class = get_manual_classification(&[3.0, 6.0], &data).unwrap();
+
+        let mut starts: Vec<f64> = vec![];
+        for bin in &class {
+            starts.push(bin.bin_start);
+        }
+        assert_eq!(starts, vec![1.0, 3.0, 6.0]);
+        assert_eq!(class.iter().filter(|bin| bin.count == 2).count(), 3);
+
+        let owned: Vec<Bin> = class.clone().into_iter().collect();
+        assert_eq!(owned, class);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_bins() {
+        let class: Classification = vec![
+            Bin {
+                bin_start: 1.0,
+                bin_end: 2.5,
+                count: 3,
+            },
+            Bin {
+                bin_start: 2.5,
+                bin_end: f64::INFINITY,
+                count: 1,
+            },
+        ];
+
+        let json = serde_json::to_string(&class).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"bin_start":1.0,"bin_end":2.5,"count":3},{"bin_start":2.5,"bin_end":null,"count":1}]"#
+        );
+
+        let finite: Classification = serde_json::from_str(
+            r#"[{"bin_start":1.0,"bin_end":2.5,"count":3},{"bin_start":2.5,"bin_end":4.0,"count":1}]"#,
+        )
+        .unwrap();
+        assert_eq!(finite[0], class[0]);
+        assert_eq!(finite[1].bin_end, 4.0);
+    }
+
+    #[test]
+    fn test_jenks_config_permutations_and_seed() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let data: Vec<f64> = (0..300).map(|_| rng.gen_range(0.0..1000.0)).collect();
+
+        assert_eq!(
+            JenksConfig::new().classify(5, &data),
+            Ok(get_jenks_classification(5, &data))
+        );
+        assert_eq!(
+            JenksConfig::new()
+                .permutations(10000)
+                .seed(123456789)
+                .breaks(5, &data),
+            Ok(get_jenks_breaks(5, &data))
+        );
+
+        let config = JenksConfig::new().permutations(50).seed(99);
+        assert_eq!(config.breaks(5, &data), config.breaks(5, &data));
+        assert_eq!(config.breaks(5, &data).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_jenks_injected_rng() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let data: Vec<f64> = (0..200).map(|_| rng.gen_range(0.0..50.0)).collect();
+        let config = JenksConfig::new().permutations(100);
+
+        let injected = config.breaks_with_rng(4, &data, &mut StdRng::seed_from_u64(11));
+        assert_eq!(
+            injected,
+            config.breaks_with_rng(4, &data, &mut StdRng::seed_from_u64(11))
+        );
+
+        // One generator can be shared across runs without reseeding
+        let mut shared = StdRng::seed_from_u64(5);
+        let first = config.classify_with_rng(4, &data, &mut shared).unwrap();
+        let second = config.classify_with_rng(4, &data, &mut shared).unwrap();
+        assert_eq!(first.len(), 4);
+        assert_eq!(second.len(), 4);
+
+        let mut breaks = vec![0_usize; 3];
+        let rng: &mut dyn RngCore = &mut shared;
+        jenks::pick_rand_breaks(&mut breaks, &10, rng);
+        assert!(breaks.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_jenks_f32_and_f64() {
+        let mut rng = StdRng::seed_from_u64(21);
+        let data_f32: Vec<f32> = (0..500)
+            .map(|_| rng.gen_range(0_u16..1000) as f32)
+            .collect();
+        let data_f64: Vec<f64> = data_f32.iter().map(|val| *val as f64).collect();
+
+        let breaks_f32: Vec<f32> = get_jenks_breaks_float(5, &data_f32);
+        let breaks_f64: Vec<f64> = get_jenks_breaks_float(5, &data_f64);
+        assert_eq!(breaks_f64, get_jenks_breaks(5, &data_f64));
+        let widened: Vec<f64> = breaks_f32.iter().map(|val| *val as f64).collect();
+        assert_eq!(widened, breaks_f64);
+
+        let class_f32: Classification<f32> = get_jenks_classification_float(5, &data_f32);
+        let class_f64: Classification = get_jenks_classification(5, &data_f64);
+        assert_eq!(class_f32.len(), class_f64.len());
+        for (bin_f32, bin_f64) in class_f32.iter().zip(class_f64.iter()) {
+            assert_eq!(bin_f32.bin_start as f64, bin_f64.bin_start);
+            assert_eq!(bin_f32.bin_end as f64, bin_f64.bin_end);
+            assert_eq!(bin_f32.count, bin_f64.count);
+        }
+
+        assert_eq!(
+            jenks::calc_gssd(&[1.0_f32, 2.0, 3.0]) as f64,
+            jenks::calc_gssd(&[1.0_f64, 2.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn test_try_classifications() {
+        let empty: Vec<f64> = vec![];
+        let with_nan: Vec<f64> = vec![1.0, f64::NAN, 3.0];
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+
+        type TryFn = fn(&[f64]) -> Result<Classification, ClassifyError>;
+        let methods: Vec<TryFn> = vec![
+            |data| try_get_quantile_classification(3, data),
+            |data| try_get_head_tail_classification(data),
+            |data| try_get_equal_interval_classification(3, data),
+            |data| try_get_st_dev_classification(1.0, data),
+            |data| try_get_hinge_classification(1.5, data),
+            |data| try_get_boxplot_classification(data),
+            |data| try_get_geometric_interval_classification(3, data),
+            |data| try_get_fisher_jenks_classification(3, data),
+            |data| try_get_maximum_breaks_classification(3, data),
+            |data| try_get_pretty_classification(3, data),
+            |data| try_get_kmeans_classification(3, data, 100),
+            |data| try_get_unique_value_classification(data, None),
+            |data| try_get_ckmeans_classification(3, data),
+            |data| try_get_jenks_classification(3, data),
+        ];
+        for method in methods {
+            assert_eq!(method(&empty), Err(ClassifyError::EmptyData));
+            assert_eq!(method(&with_nan), Err(ClassifyError::NonFinite));
+            assert!(method(&data).is_ok());
+        }
+
+        assert_eq!(
+            try_get_quantile_classification(0, &data),
+            Err(ClassifyError::ZeroBins)
+        );
+        assert_eq!(
+            try_get_kmeans_classification(0, &data, 10),
+            Err(ClassifyError::ZeroBins)
+        );
+        assert_eq!(
+            try_get_st_dev_classification(0.0, &data),
+            Err(ClassifyError::NonPositiveInterval)
+        );
+        assert_eq!(
+            try_get_hinge_classification(f64::INFINITY, &data),
+            Err(ClassifyError::NonFinite)
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_jenks_parallel_determinism() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let data: Vec<f64> = (0..2000).map(|_| rng.gen_range(0.0..500.0)).collect();
+        let config = JenksConfig::new().permutations(500);
+
+        let run = |threads: usize| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap()
+                .install(|| config.breaks(6, &data))
+        };
+        let single = run(1);
+        assert_eq!(single, run(4));
+        assert_eq!(single, run(8));
+        assert_eq!(single.unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_prefix_sums_gvf() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let mut data: Vec<f64> = (0..1000).map(|_| rng.gen_range(-50.0..5000.0)).collect();
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let gssd = jenks::calc_gssd(&data);
+        let sums = jenks::PrefixSums::new(&data);
+
+        for breaks in [vec![], vec![500], vec![10, 200, 999], vec![1, 2, 3, 4]] {
+            let direct = jenks::calc_gvf(&breaks, &data, &gssd);
+            assert!((sums.gvf(&breaks, &gssd) - direct).abs() < 1e-12);
+        }
+        assert_eq!(sums.ssd(3, 3), 0.0);
+
+        let small: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+        let small_sums = jenks::PrefixSums::new(&small);
+        assert!((small_sums.gvf(&[1, 3, 4], &37.5) - 0.9333333333333333).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_pick_rand_breaks_exhaustive() {
+        let num_vals = 1000;
+        let mut breaks: Vec<usize> = vec![0; num_vals - 1];
+        let mut rng = StdRng::seed_from_u64(123456789);
+
+        jenks::pick_rand_breaks(&mut breaks, &num_vals, &mut rng);
+
+        let expected: Vec<usize> = (1..num_vals).collect();
+        assert_eq!(breaks, expected);
+    }
+
+    #[test]
+    fn test_ssd_precision_for_large_values() {
+        let noise = [4.0, 7.0, 13.0, 16.0, 4.0, 7.0, 13.0, 16.0];
+        let data: Vec<f64> = noise.iter().map(|n| 1e9 + n).collect();
+
+        // The squared deviations from the mean of 1e9 + 10 add up to 180
+        let mut sum = 0.0;
+        let mut sq_sum = 0.0;
+        for val in &data {
+            sum += val;
+            sq_sum += val * val;
+        }
+        let naive = sq_sum - sum * sum / data.len() as f64;
+        assert!((naive - 180.0).abs() > 1.0);
+
+        assert!((jenks::calc_gssd(&data) - 180.0).abs() < 1e-5);
+        assert!(jenks::calc_gvf(&[4], &data, &180.0).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_slice_inputs() {
+        let buffer: Vec<f64> = vec![100.0, 1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 100.0];
+        let window: &[f64] = &buffer[1..7];
+        let owned: Vec<f64> = window.to_vec();
+
+        assert_eq!(get_jenks_breaks(3, window), get_jenks_breaks(3, &owned));
+        assert_eq!(
+            standard_deviation::calc_st_dev(window),
+            standard_deviation::calc_st_dev(&owned)
+        );
+        assert_eq!(get_head_tail_breaks(window), get_head_tail_breaks(&owned));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_classification_to_json() {
+        let class: Classification = vec![
+            Bin {
+                bin_start: f64::NEG_INFINITY,
+                bin_end: 0.0,
+                count: 1,
+            },
+            Bin {
+                bin_start: 0.0,
+                bin_end: 10.0,
+                count: 4,
+            },
+        ];
+        let json = classification_to_json(&class);
+
+        assert!(json["min"].is_null());
+        assert_eq!(json["max"], 10.0);
+        assert_eq!(json["breaks"], serde_json::json!([0.0]));
+        assert!(json["bins"][0]["min"].is_null());
+        assert_eq!(json["bins"][1]["count"], 4);
+
+        let empty = classification_to_json(&vec![]);
+        assert!(empty["min"].is_null() && empty["max"].is_null());
+        assert_eq!(empty["bins"], serde_json::json!([]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_classification_csv_round_trip() {
+        let class: Classification = vec![
+            Bin {
+                bin_start: 0.1,
+                bin_end: 1.0 / 3.0,
+                count: 2,
+            },
+            Bin {
+                bin_start: 1.0 / 3.0,
+                bin_end: 1e300,
+                count: 5,
+            },
+        ];
+        let mut csv: Vec<u8> = vec![];
+        write_classification_csv(&mut csv, &class).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("bin_start,bin_end,count"));
+        let parsed: Classification = lines
+            .map(|line| {
+                let fields: Vec<&str> = line.split(',').collect();
+                Bin {
+                    bin_start: fields[0].parse().unwrap(),
+                    bin_end: fields[1].parse().unwrap(),
+                    count: fields[2].parse().unwrap(),
+                }
+            })
+            .collect();
+        assert_eq!(parsed, class);
+    }
+
+    #[cfg(feature = "colors")]
+    #[test]
+    fn test_assign_colors() {
+        let data: Vec<f64> = (0..20).map(|x| x as f64).collect();
+        let class = get_equal_interval_classification(5, &data);
+        let palette: Vec<[u8; 3]> = vec![[0, 0, 0], [100, 200, 50]];
+
+        let colors = assign_colors(&class, &palette);
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors[0], [0, 0, 0]);
+        assert_eq!(colors[2], [50, 100, 25]);
+        assert_eq!(colors[4], [100, 200, 50]);
+
+        let long_palette: Vec<[u8; 3]> = (0..8).map(|i| [i, i, i]).collect();
+        assert_eq!(assign_colors(&class, &long_palette)[4], [4, 4, 4]);
+        assert_eq!(assign_colors(&class, &[[9, 9, 9]]), vec![[9, 9, 9]; 5]);
+        assert!(assign_colors(&class, &[]).is_empty());
+
+        assert_eq!(class.color_for_value(19.0, &palette), Some([100, 200, 50]));
+        assert_eq!(class.color_for_value(f64::NAN, &palette), None);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_ndarray_views() {
+        use ndarray::{s, Array1};
+
+        let data: Vec<f64> = vec![1.0, 9.0, 2.0, 9.0, 4.0, 9.0, 5.0, 9.0, 7.0, 9.0, 8.0, 9.0];
+        let array = Array1::from(data.clone());
+        let strided = array.slice(s![..;2]);
+        let odd: Vec<f64> = data.iter().step_by(2).copied().collect();
+
+        assert_eq!(
+            get_jenks_classification_ndarray(3, &strided),
+            get_jenks_classification(3, &odd)
+        );
+        assert_eq!(
+            get_quantile_classification_ndarray(4, &array.view()),
+            get_quantile_classification(4, &data)
+        );
+        assert_eq!(
+            get_manual_classification_ndarray(&[5.0], &strided).unwrap(),
+            get_manual_classification(&[5.0], &odd).unwrap()
+        );
+    }
+
+    #[cfg(feature = "plotters")]
+    #[test]
+    fn test_render_histogram() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, f64::NAN];
+        let class = get_manual_classification(&[3.0, 6.0], &data[..6]).unwrap();
+        let path = std::env::temp_dir().join("classify_test_render_histogram.png");
+        let path = path.to_str().unwrap();
+
+        render_histogram(&class, &data, path).unwrap();
+        assert!(std::fs::metadata(path).unwrap().len() > 0);
+        std::fs::remove_file(path).unwrap();
+
+        assert!(render_histogram(&vec![], &data, path).is_err());
+    }
+
+    #[test]
+    fn test_drop_empty_bins_over_binned() {
+        let data: Vec<f64> = vec![0.0, 0.1, 0.2, 5.0, 9.9, 10.0];
+        let mut class = get_equal_interval_classification(10, &data);
+        assert_eq!(class.empty_bins(), vec![1, 2, 3, 4, 6, 7, 8]);
+
+        class.drop_empty_bins();
+        assert!(class.empty_bins().is_empty());
+        assert_eq!(class.breaks(), vec![5.0, 9.0]);
+        assert_eq!(class[0].bin_start, 0.0);
+        assert_eq!(class[2].bin_end, 10.0);
+        assert_eq!(class.iter().map(|bin| bin.count).sum::<u64>(), 6);
+        for val in &data {
+            assert_eq!(
+                class.classify(*val),
+                breaks_to_classification(&class.breaks(), &data).classify(*val)
+            );
+        }
+
+        let mut leading: Classification = breaks_to_classification(&[1.0, 2.0], &[2.5, 3.0]);
+        leading[0].bin_start = 0.0;
+        leading.drop_empty_bins();
+        assert_eq!(
+            leading,
+            vec![Bin {
+                bin_start: 0.0,
+                bin_end: 3.0,
+                count: 2
+            }]
+        );
+
+        let mut all_empty: Classification = vec![
+            Bin {
+                bin_start: 0.0,
+                bin_end: 1.0,
+                count: 0,
+            },
+            Bin {
+                bin_start: 1.0,
+                bin_end: 2.0,
+                count: 0,
+            },
+        ];
+        all_empty.drop_empty_bins();
+        assert_eq!(all_empty.len(), 1);
+        assert_eq!(all_empty[0].bin_end, 2.0);
+    }
+
+    #[test]
+    fn test_percentages_and_cumulative_counts() {
+        let mut rng = StdRng::seed_from_u64(123456789);
+        let data: Vec<f64> = (0..997).map(|_| rng.gen_range(0.0..100.0)).collect();
+        let class = get_quantile_classification(7, &data);
+
+        let total: f64 = class.percentages().iter().sum();
+        assert!((total - 1.0).abs() < 1e-12);
+        assert_eq!(class.cumulative_counts().last(), Some(&997));
+
+        let mut empty = class.clone();
+        for bin in empty.iter_mut() {
+            bin.count = 0;
+        }
+        assert_eq!(empty.percentages(), vec![0.0; 7]);
+        assert_eq!(empty.cumulative_counts(), vec![0; 7]);
+        assert!(Classification::new().percentages().is_empty());
+    }
+
+    #[test]
+    fn test_merge_bins() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+        let mut class = get_manual_classification(&[3.0, 6.0], &data).unwrap();
+
+        assert_eq!(class.merge_bins(1, 3), Err(ClassifyError::NonAdjacentBins));
+        assert_eq!(class.merge_bins(2, 3), Err(ClassifyError::NonAdjacentBins));
+        assert_eq!(class.merge_bins(1, 1), Err(ClassifyError::NonAdjacentBins));
+        assert_eq!(class.len(), 3);
+
+        class.merge_bins(1, 0).unwrap();
+        assert_eq!(class, get_manual_classification(&[6.0], &data).unwrap());
+    }
+
+    #[test]
+    fn test_classification_method_dispatch() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 10.0, 11.0];
+        for method in ClassificationMethod::all() {
+            let class = method.classify(3, &data).unwrap();
+            assert_eq!(
+                class.iter().map(|bin| bin.count).sum::<u64>(),
+                data.len() as u64,
+                "{} lost data points",
+                method.name()
+            );
+            assert_eq!(
+                method.classify(3, &[] as &[f64]),
+                Err(ClassifyError::EmptyData)
+            );
+            assert_eq!(
+                method.classify(3, &[1.0, f64::NAN]),
+                Err(ClassifyError::NonFinite)
+            );
+        }
+
+        assert_eq!(
+            ClassificationMethod::Quantile.classify(4, &data).unwrap(),
+            get_quantile_classification(4, &data)
+        );
+        assert_eq!(
+            ClassificationMethod::StdDev(0.5)
+                .classify(0, &data)
+                .unwrap(),
+            get_st_dev_classification(0.5, &data)
+        );
+        assert_eq!(
+            ClassificationMethod::Manual(vec![6.0, 3.0]).classify(3, &data),
+            Err(ClassifyError::UnsortedBreaks)
+        );
+        assert_eq!(
+            ClassificationMethod::Jenks.classify(0, &data),
+            Err(ClassifyError::ZeroBins)
+        );
+    }
+
+    #[test]
+    fn test_classify_with_result() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, f64::INFINITY, f64::NAN];
+        let method = ClassificationMethod::FisherJenks;
+        let result = method.classify_with_result(3, &data).unwrap();
+
+        assert_eq!(result.method, method);
+        assert_eq!(result.requested_bins, 3);
+        assert_eq!(result.actual_bins, result.classification.len());
+        assert_eq!(
+            result.classification,
+            get_fisher_jenks_classification(3, &data[..6])
+        );
+        assert_eq!(
+            result.gvf,
+            goodness_of_variance_fit(&result.classification, &data[..6])
+        );
+        assert_eq!(
+            result.warnings,
+            vec![Warning::DroppedNonFinite { count: 2 }]
+        );
+
+        let clean = method.classify_with_result(3, &data[..6]).unwrap();
+        assert!(clean.warnings.is_empty());
+        assert_eq!(
+            method.classify_with_result(3, &[f64::NAN]),
+            Err(ClassifyError::EmptyData)
+        );
+    }
+
+    #[test]
+    fn test_validate_classification() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+        for method in ClassificationMethod::all() {
+            if method == ClassificationMethod::UniqueValue {
+                continue;
+            }
+            let class = method.classify(3, &data).unwrap();
+            assert_eq!(class.validate(), Ok(()), "{} is invalid", method.name());
+        }
+        assert_eq!(Classification::new().validate(), Ok(()));
+
+        let mut inverted = get_equal_interval_classification(2, &data);
+        inverted[0].bin_end = 0.0;
+        inverted[1].bin_start = 0.0;
+        assert_eq!(inverted.validate(), Err(ClassifyError::NonContiguousBins));
+
+        let mut gap = get_equal_interval_classification(2, &data);
+        gap[1].bin_start += 0.5;
+        assert_eq!(gap.validate(), Err(ClassifyError::NonContiguousBins));
+
+        let mut nan = get_equal_interval_classification(2, &data);
+        nan[1].bin_end = f64::NAN;
+        assert_eq!(nan.validate(), Err(ClassifyError::NonContiguousBins));
+    }
+
+    #[test]
+    fn test_snap_breaks_keeps_counts() {
+        let mut rng = StdRng::seed_from_u64(123456789);
+        let data: Vec<f64> = (0..500).map(|_| rng.gen_range(-50.0..250.0)).collect();
+
+        for precision in [
+            SnapMode::Nice,
+            SnapMode::Decimals(0),
+            SnapMode::Decimals(-2),
+        ] {
+            let mut class = get_quantile_classification(6, &data);
+            class.snap_breaks(&data, precision);
+
+            assert_eq!(class.iter().map(|bin| bin.count).sum::<u64>(), 500);
+            assert_eq!(class, breaks_to_classification(&class.breaks(), &data));
+            assert_eq!(class.validate(), Ok(()));
+        }
+
+        let mut class = get_quantile_classification(6, &data);
+        class.snap_breaks(&data, SnapMode::Decimals(-2));
+        assert_eq!(class.breaks(), vec![0.0, 100.0, 200.0]);
+
+        let small: Vec<f64> = vec![0.0, 0.12, 0.29, 0.31, 0.58, 0.9];
+        let mut class = breaks_to_classification(&[0.2999999, 0.6123], &small);
+        class.snap_breaks(&small, SnapMode::Decimals(1));
+        assert_eq!(class.breaks(), vec![0.3, 0.6]);
+        class.snap_breaks(&small, SnapMode::Nice);
+        assert_eq!(class.breaks(), vec![0.4, 0.6]);
+    }
+
+    #[test]
+    fn test_open_ended_bins() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+        let mut class = get_manual_classification(&[3.0, 6.0], &data).unwrap();
+        class.with_open_ends();
+
+        assert_eq!(class.validate(), Ok(()));
+        assert_eq!(class.classify(f64::MIN), Some(0));
+        assert_eq!(class.classify(f64::MAX), Some(2));
+        assert_eq!(class.classify(f64::NAN), None);
+        assert_eq!(class.breaks(), vec![3.0, 6.0]);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&class).unwrap();
+            assert!(!json.contains("inf"));
+            let parsed: Classification = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, class);
+        }
+    }
+
+    #[test]
+    fn test_diverging_classification() {
+        let mut rng = StdRng::seed_from_u64(123456789);
+        let data: Vec<f64> = (0..400).map(|_| rng.gen_range(-2.0..5.0)).collect();
+
+        let width = get_diverging_classification(3, &data, 0.0, DivergingSpacing::EqualWidth);
+        assert_eq!(width.len(), 6);
+        assert_eq!(width[3].bin_start, 0.0);
+        assert_eq!(width[0].bin_start, -width[5].bin_end);
+        assert_eq!(width.validate(), Ok(()));
+        assert_eq!(width.iter().map(|bin| bin.count).sum::<u64>(), 400);
+
+        let count = get_diverging_classification(3, &data, 0.0, DivergingSpacing::EqualCount);
+        assert_eq!(count.len(), 6);
+        assert_eq!(count[3].bin_start, 0.0);
+        assert_eq!(count.validate(), Ok(()));
+        let below: u64 = count[..3].iter().map(|bin| bin.count).sum();
+        for bin in &count[..3] {
+            assert!((bin.count as f64 - below as f64 / 3.0).abs() <= 1.0);
+        }
+
+        let positive: Vec<f64> = vec![1.0, 2.0, 3.0];
+        let one_sided =
+            get_diverging_classification(2, &positive, 0.0, DivergingSpacing::EqualCount);
+        assert_eq!(one_sided[0].count + one_sided[1].count, 0);
+        assert_eq!(one_sided.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_weighted_jenks_matches_unweighted() {
+        let mut rng = StdRng::seed_from_u64(123456789);
+        let data: Vec<f64> = (0..300).map(|_| rng.gen_range(0.0..1000.0)).collect();
+        let ones: Vec<f64> = vec![1.0; data.len()];
+
+        assert_eq!(
+            get_weighted_jenks_classification(5, &data, &ones),
+            get_jenks_classification(5, &data)
+        );
+        assert_eq!(
+            get_weighted_jenks_breaks(5, &data, &ones),
+            get_jenks_breaks(5, &data)
+        );
+
+        let values: Vec<f64> = vec![1.0, 3.0, 3.0, 9.0];
+        let weights: Vec<f64> = vec![2.0, 0.5, 1.0, 0.0];
+        let class = get_weighted_jenks_classification(3, &values, &weights);
+        assert_eq!(class.len(), 2);
+        assert_eq!(class[1].bin_end, 3.0);
+        assert_eq!(class[1].count, 2);
+
+        assert_eq!(
+            try_get_weighted_jenks_classification(3, &values, &[1.0, -1.0, 1.0, 1.0]),
+            Err(ClassifyError::InvalidWeights)
+        );
+        assert_eq!(
+            try_get_weighted_jenks_classification(3, &values, &[0.0; 4]),
+            Err(ClassifyError::EmptyData)
+        );
+    }
+
+    #[test]
+    fn test_trimmed_jenks_outliers() {
+        let mut rng = StdRng::seed_from_u64(123456789);
+        let mut data: Vec<f64> = (0..196).map(|_| rng.gen_range(0.0..100.0)).collect();
+        data.extend([-1e6, -1e5, 1e5, 1e6]);
+
+        let (class, num_outliers) = get_trimmed_jenks_classification(4, &data, 0.01);
+        assert_eq!(num_outliers, 4);
+        assert_eq!(class.len(), 4);
+        assert_eq!(class[0].bin_start, -1e6);
+        assert_eq!(class[3].bin_end, 1e6);
+        assert_eq!(class.iter().map(|bin| bin.count).sum::<u64>(), 200);
+        for brk in class.breaks() {
+            assert!(brk > 0.0 && brk < 100.0);
+        }
+
+        let untrimmed = get_jenks_classification(4, &data);
+        assert!(untrimmed.breaks().iter().any(|brk| brk.abs() >= 1e5));
+
+        let (_, kept_one) = get_trimmed_jenks_classification(2, &[1.0, 2.0, 3.0], 0.9);
+        assert_eq!(kept_one, 2);
+    }
+
+    #[test]
+    fn test_data_summary() {
+        let data: Vec<f64> = vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        let summary = DataSummary::from_data(&data);
+
+        assert_eq!(summary.count, 4);
+        assert_eq!(summary.min, 1e9 + 4.0);
+        assert_eq!(summary.max, 1e9 + 16.0);
+        assert_eq!(summary.mean, 1e9 + 10.0);
+        assert!((summary.variance - 30.0).abs() < 1e-6);
+        assert!(summary.skewness.abs() < 1e-6);
+        // Population excess kurtosis of the points -6, -3, 3, 6
+        assert!((summary.kurtosis - (4.0 * 2754.0 / (90.0 * 90.0) - 3.0)).abs() < 1e-6);
+
+        let skewed = DataSummary::from_data(&[1.0, 1.0, 1.0, 1.0, 10.0]);
+        assert!((skewed.skewness - 1.5).abs() < 1e-12);
+        assert!((skewed.kurtosis - 0.25).abs() < 1e-12);
+
+        let empty: Vec<f64> = vec![];
+        let summary = DataSummary::from_data(&empty);
+        assert_eq!(summary.count, 0);
+        assert!(summary.mean.is_nan() && summary.variance.is_nan());
+    }
+
+    #[test]
+    fn test_empirical_cdf_and_percentile_of() {
+        let data: Vec<f64> = vec![9.0, 1.0, 3.0, 3.0, 7.0];
+
+        assert_eq!(empirical_cdf(&data, 3.0), 0.6);
+        assert_eq!(empirical_cdf(&data, 2.9), 0.2);
+        assert_eq!(percentile_of(&data, 25.0), 3.0);
+        assert_eq!(percentile_of(&data, 87.5), 8.0);
+        assert_eq!(percentile_of(&data, -10.0), 1.0);
+        assert_eq!(percentile_of(&data, 200.0), 9.0);
+
+        let empty: Vec<f64> = vec![];
+        assert!(empirical_cdf(&empty, 1.0).is_nan());
+        assert!(percentile_of(&empty, 50.0).is_nan());
+    }
+
+    #[test]
+    fn test_detect_outliers_iqr() {
+        let data: Vec<f64> = vec![3.0, 1.0, 2.0, 4.0, 250.0, 5.0, 6.0, 7.0, 8.0];
+        assert_eq!(detect_outliers_iqr(&data, 1.5), vec![4]);
+        assert_eq!(detect_outliers_iqr(&data, 3.0), vec![4]);
+
+        let clean: Vec<u32> = vec![10, 11, 12, 13, 14, 15, 16, 17, 18, 19];
+        assert!(detect_outliers_iqr(&clean, 1.5).is_empty());
+
+        let empty: Vec<f64> = vec![];
+        assert!(detect_outliers_iqr(&empty, 1.5).is_empty());
+    }
+
+    #[test]
+    fn test_recount() {
+        let reference: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let class: Classification = get_equal_interval_classification(3, &reference);
+        let next_year: Vec<f64> = vec![0.0, 5.0, 7.0, 7.5, 8.0, 9.5, 12.0, f64::NAN];
+
+        let recounted: Classification = class.recount(&next_year);
+        assert_eq!(recounted.breaks(), class.breaks());
+        assert_eq!(recounted[0].bin_start, class[0].bin_start);
+        assert_eq!(recounted[2].bin_end, class[2].bin_end);
+        let counts: Vec<u64> = recounted.iter().map(|bin| bin.count).collect();
+        assert_eq!(counts, vec![1, 1, 5]);
+    }
+
+    #[test]
+    fn test_legend_labels() {
+        let mut class: Classification = vec![
+            Bin {
+                bin_start: -0.004,
+                bin_end: 0.125,
+                count: 2,
+            },
+            Bin {
+                bin_start: 0.125,
+                bin_end: 12345.678,
+                count: 2,
+            },
+            Bin {
+                bin_start: 12345.678,
+                bin_end: 1234567.0,
+                count: 2,
+            },
+        ];
+
+        let labels = class.legend_labels(LabelOptions::new());
+        assert_eq!(
+            labels,
+            vec!["0.00 - 0.12", "0.12 - 12345.68", "12345.68 - 1234567.00"]
+        );
+
+        let opts = LabelOptions::new()
+            .precision(1)
+            .thousands_separator(' ')
+            .suffix("%");
+        assert_eq!(
+            class.legend_labels(opts),
+            vec![
+                "0.0% - 0.1%",
+                "0.1% - 12 345.7%",
+                "12 345.7% - 1 234 567.0%"
+            ]
+        );
+
+        class.with_open_ends();
+        let labels = class.legend_labels(LabelOptions::new().precision(0));
+        assert_eq!(labels, vec!["< 0", "0 - 12346", "> 12346"]);
+
+        let unique: Classification = get_unique_value_classification(&[3, 3, 5], None);
+        let labels = unique.legend_labels(LabelOptions::new().precision(0).open_ends(true));
+        assert_eq!(labels, vec!["< 3", "> 5"]);
+    }
+
+    #[test]
+    fn test_assign_all() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+        let class: Classification = get_equal_interval_classification(4, &data);
+
+        let assignments = class.assign_all(&data, None);
+        assert_eq!(assignments, vec![0, 0, 1, 2, 3, 3, 3]);
+        for (val, idx) in data.iter().zip(&assignments) {
+            assert_eq!(class.classify(*val), Some(*idx));
+        }
+
+        let new_data: Vec<f64> = vec![f64::NAN, 0.0, 3.0, 12.0];
+        let mut out_of_range: Vec<usize> = vec![];
+        assert_eq!(
+            class.assign_all(&new_data, Some(&mut out_of_range)),
+            vec![0, 0, 1, 3]
+        );
+        assert_eq!(out_of_range, vec![0, 1, 3]);
+
+        let empty: Classification = vec![];
+        let mut out_of_range: Vec<usize> = vec![];
+        assert!(empty.assign_all(&data, Some(&mut out_of_range)).is_empty());
+        assert!(out_of_range.is_empty());
+    }
+
+    #[test]
+    fn test_bin_hash_and_eq() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(bin: &Bin) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            bin.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = Bin {
+            bin_start: 0.0,
+            bin_end: 2.5,
+            count: 3,
+        };
+        let b = Bin {
+            bin_start: -0.0,
+            bin_end: 5.0 / 2.0,
+            count: 3,
+        };
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        let nan_a = Bin {
+            bin_start: f64::NAN,
+            bin_end: 1.0,
+            count: 0,
+        };
+        let nan_b = Bin {
+            bin_start: -f64::NAN,
+            bin_end: 1.0,
+            count: 0,
+        };
+        assert_eq!(nan_a, nan_b);
+        assert_eq!(hash_of(&nan_a), hash_of(&nan_b));
+        assert_ne!(
+            a,
+            Bin {
+                bin_start: 0.0,
+                bin_end: 2.5,
+                count: 4
+            }
+        );
+
+        let mut labels: HashMap<Bin, &str> = HashMap::new();
+        labels.insert(a, "low");
+        assert_eq!(labels.get(&b), Some(&"low"));
+    }
+
+    #[test]
+    fn test_arithmetic_interval_widths() {
+        let data: Vec<f64> = vec![10.0, 12.0, 15.0, 30.0, 41.0, 55.0, 70.0];
+        let breaks = get_arithmetic_interval_breaks(5, &data);
+
+        // The range of 60 is split into widths of 4, 8, 12, 16, and 20
+        assert_eq!(breaks, vec![14.0, 22.0, 34.0, 50.0]);
+
+        let class = get_arithmetic_interval_classification(5, &data);
+        let widths: Vec<f64> = class
+            .iter()
+            .map(|bin| bin.bin_end - bin.bin_start)
+            .collect();
+        assert_eq!(widths, vec![4.0, 8.0, 12.0, 16.0, 20.0]);
+
+        let constant: Vec<f64> = vec![3.0, 3.0, 3.0];
+        assert!(get_arithmetic_interval_breaks(4, &constant).is_empty());
+        assert_eq!(
+            get_arithmetic_interval_classification(4, &constant).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_nested_means() {
+        let data: Vec<f64> = (0..64).map(|i| (i * i) as f64).collect();
+
+        for levels in 0..4 {
+            let breaks = get_nested_means_breaks(levels, &data);
+            assert_eq!(breaks.len(), (1 << levels) - 1);
+            assert!(breaks.windows(2).all(|pair| pair[0] < pair[1]));
+            let class = get_nested_means_classification(levels, &data);
+            assert_eq!(class.len(), 1 << levels);
+        }
+
+        let repeated: Vec<f64> = vec![1.0, 1.0, 1.0, 5.0];
+        assert_eq!(get_nested_means_breaks(3, &repeated), vec![2.0]);
+    }
+
+    #[test]
+    fn test_classify_batch() {
+        let datasets: Vec<Vec<f64>> = (1..12)
+            .map(|n| (0..(n * 5)).map(|i| ((i * 7919) % 101) as f64).collect())
+            .collect();
+
+        for method in [
+            ClassificationMethod::Jenks,
+            ClassificationMethod::FisherJenks,
+        ] {
+            let results = classify_batch(&method, 4, &datasets);
+            assert_eq!(results.len(), datasets.len());
+            for (result, data) in results.iter().zip(&datasets) {
+                assert_eq!(result, &method.classify(4, data));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bivariate_classification() {
+        let x: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let y: Vec<f64> = vec![9.0, 1.0, 5.0, 2.0, 8.0, 3.0, 7.0, 4.0, 6.0];
+        let method = ClassificationMethod::EqualInterval;
+
+        let result = get_bivariate_classification(3, 3, &x, &y, &method).unwrap();
+        assert_eq!(result.x, get_equal_interval_classification(3, &x));
+        assert_eq!(result.y, get_equal_interval_classification(3, &y));
+        assert_eq!(result.classes.len(), x.len());
+        for (i, (x_class, y_class)) in result.classes.iter().enumerate() {
+            assert_eq!(result.x.classify(x[i]), Some(*x_class));
+            assert_eq!(result.y.classify(y[i]), Some(*y_class));
+        }
+
+        assert_eq!(
+            get_bivariate_classification(3, 3, &x, &y[1..], &method),
+            Err(ClassifyError::MismatchedLengths)
+        );
+    }
+
+    #[test]
+    fn test_suggest_bin_count() {
+        let data: Vec<f64> = (1..=1000).map(|i| i as f64).collect();
+        assert_eq!(suggest_bin_count(&data, BinCountRule::Sturges), 11);
+        assert_eq!(suggest_bin_count(&data, BinCountRule::SquareRoot), 32);
+        assert_eq!(suggest_bin_count(&data, BinCountRule::Scott), 10);
+        assert_eq!(suggest_bin_count(&data, BinCountRule::FreedmanDiaconis), 10);
+
+        let rules = [
+            BinCountRule::Sturges,
+            BinCountRule::Scott,
+            BinCountRule::FreedmanDiaconis,
+            BinCountRule::SquareRoot,
+        ];
+        let empty: Vec<f64> = vec![];
+        let constant: Vec<f64> = vec![2.0; 10];
+        for rule in rules {
+            assert_eq!(suggest_bin_count(&empty, rule), 1);
+            assert!(suggest_bin_count(&constant, rule) >= 1);
+        }
+        assert_eq!(suggest_bin_count(&constant, BinCountRule::Scott), 1);
+        assert_eq!(
+            suggest_bin_count(&constant, BinCountRule::FreedmanDiaconis),
+            1
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_break_stability() {
+        let stable: Vec<f64> = (0..30)
+            .map(|i| {
+                if i < 15 {
+                    i as f64 * 0.1
+                } else {
+                    100.0 + i as f64 * 0.1
+                }
+            })
+            .collect();
+        let intervals = bootstrap_break_stability(2, &stable, 30, 7);
+        assert_eq!(intervals.len(), 1);
+        assert!(intervals[0].0 >= 101.5 && intervals[0].1 <= 102.9);
+        assert_eq!(intervals, bootstrap_break_stability(2, &stable, 30, 7));
+
+        let spread: Vec<f64> = (0..30).map(|i| i as f64).collect();
+        let wide = bootstrap_break_stability(2, &spread, 30, 7);
+        assert!(wide[0].0 <= wide[0].1);
+
+        let empty: Vec<f64> = vec![];
+        assert!(bootstrap_break_stability(3, &empty, 10, 7).is_empty());
+
+        let with_nan: Vec<f64> = vec![1.0, f64::NAN, 3.0, 4.0];
+        assert!(bootstrap_break_stability(2, &with_nan, 10, 7).is_empty());
+        assert!(bootstrap_break_stability(2, &[1.0, f64::INFINITY, 3.0], 10, 7).is_empty());
+    }
+
+    #[test]
+    fn test_max_entropy_ties() {
+        // Quantiles would split the run of 2s, which maximum entropy cannot
+        let data: Vec<f64> = vec![1.0, 2.0, 2.0, 2.0, 2.0, 2.0, 3.0, 4.0, 5.0];
+        let (class, entropy) = get_max_entropy_classification_with_entropy(3, &data);
+
+        let counts: Vec<u64> = class.iter().map(|bin| bin.count).collect();
+        assert_eq!(counts.iter().sum::<u64>(), 9);
+        assert_eq!(counts, vec![1, 5, 3]);
+        let expected: f64 = counts
+            .iter()
+            .map(|count| {
+                let share = *count as f64 / 9.0;
+                -share * share.ln()
+            })
+            .sum();
+        assert!((entropy - expected).abs() < 1e-12);
+        assert!(entropy < 3.0_f64.ln());
+    }
+
+    #[test]
+    fn test_per_class_variance() {
+        let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 20.0];
+        let class: Classification = get_manual_classification(&[4.0, 7.0], &data).unwrap();
+
+        // [1, 2] has mean 1.5, [4, 5] has mean 4.5, and [7, 8, 20] has mean 35 / 3
+        let expected = [0.5, 0.5, 196.0 / 9.0 + 121.0 / 9.0 + 625.0 / 9.0];
+        let result = per_class_variance(&class, &data);
+        assert_eq!(result.len(), 3);
+        for (ssd, expected) in result.iter().zip(&expected) {
+            assert!((ssd - expected).abs() < 1e-9);
+        }
+
+        let gvf = 1.0 - result.iter().sum::<f64>() / jenks::calc_gssd(&data);
+        assert!((goodness_of_variance_fit(&class, &data) - gvf).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_find_natural_gaps() {
+        let data: Vec<f64> = vec![5.0, 1.0, 2.0, 2.0, 3.0, 11.0, 12.0, 13.0, 30.0, 31.0];
+        assert_eq!(find_natural_gaps(&data, 1.5), vec![8.0, 21.5]);
+        assert_eq!(find_natural_gaps(&data, 4.0), vec![21.5]);
+        assert!(find_natural_gaps(&data, 10.0).is_empty());
+
+        let even: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        assert!(find_natural_gaps(&even, 1.0).is_empty());
+        assert!(find_natural_gaps(&[4.0, 4.0], 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_gvf_significance() {
+        let data: Vec<f64> = (0..60)
+            .map(|i| (i / 20) as f64 * 10.0 + (i % 20) as f64 * 0.1)
+            .collect();
+
+        let jenks_class = get_jenks_classification(3, &data);
+        let p_jenks = gvf_significance(&jenks_class, &data, 500, 1);
+        assert!(p_jenks < 0.01);
+        assert_eq!(p_jenks, gvf_significance(&jenks_class, &data, 500, 1));
+
+        // Breaks placed inside the clusters are beaten by many random breaks
+        let poor_class = get_manual_classification(&[1.0, 21.0], &data).unwrap();
+        assert!(gvf_significance(&poor_class, &data, 500, 1) > 0.2);
+
+        let single_bin = get_equal_interval_classification(1, &data);
+        assert_eq!(gvf_significance(&single_bin, &data, 500, 1), 1.0);
+    }
+
+    #[test]
+    fn test_prepared_data() {
+        let data: Vec<f64> = (0..200)
+            .map(|i| ((i * 37 % 101) as f64).powf(1.3))
+            .collect();
+        let prepared = PreparedData::new(&data);
+
+        for num_bins in 1..=8 {
+            assert_eq!(
+                prepared.jenks_breaks(num_bins),
+                get_jenks_breaks(num_bins, &data)
+            );
+        }
+        assert_eq!(prepared.sorted_data().len(), data.len());
+        assert_eq!(prepared.try_jenks_breaks(0), Err(ClassifyError::ZeroBins));
+
+        let empty: Vec<f64> = vec![];
+        assert_eq!(
+            PreparedData::try_new(&empty).unwrap_err(),
+            ClassifyError::EmptyData
+        );
+        assert_eq!(
+            PreparedData::try_new(&[1.0, f64::NAN]).unwrap_err(),
+            ClassifyError::NonFinite
+        );
+    }
+
+    #[test]
+    fn test_jackknife_break_influence() {
+        let data: Vec<f64> = vec![
+            10.0, 1.0, 12.0, 2.0, 100.0, 3.0, 11.0, 1.0, 12.0, 2.5, 10.5, 1.5,
+        ];
+
+        let influence = jackknife_break_influence(3, &data);
+        assert_eq!(influence.len(), data.len());
+        let most_influential = (0..data.len())
+            .max_by(|a, b| influence[*a].partial_cmp(&influence[*b]).unwrap())
+            .unwrap();
+        assert_eq!(most_influential, 4);
+        // Both copies of a repeated value share the same influence
+        assert_eq!(influence[1], influence[7]);
+        assert_eq!(influence[2], influence[8]);
+
+        assert_eq!(jackknife_break_influence(1, &data), vec![0.0; data.len()]);
+        assert_eq!(jackknife_break_influence(3, &[5.0]), vec![0.0]);
+        let empty: Vec<f64> = vec![];
+        assert!(jackknife_break_influence(3, &empty).is_empty());
+    }
+
+    #[test]
+    fn test_information_loss() {
+        let data: Vec<f64> = (0..100)
+            .map(|i| ((i * 37 % 101) as f64).powf(1.3))
+            .collect();
+
+        let mut prev_loss = f64::INFINITY;
+        for num_bins in 1..=6 {
+            let classification = get_equal_interval_classification(num_bins, &data);
+            let loss = information_loss(&classification, &data);
+            assert!(loss < prev_loss);
+            assert!(
+                (loss - (1.0 - goodness_of_variance_fit(&classification, &data))).abs() < 1e-12
+            );
+            prev_loss = loss;
+        }
+
+        let mut sorted = data.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let classification = get_jenks_classification(4, &data);
+        let breaks: Vec<usize> = classification
+            .breaks()
+            .iter()
+            .map(|brk| sorted.partition_point(|val| val < brk))
+            .collect();
+        let gssd = jenks::calc_gssd(&sorted);
+        let loss = information_loss(&classification, &data);
+        assert!((loss - (1.0 - jenks::calc_gvf(&breaks, &sorted, &gssd))).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_recommend_method() {
+        let uniform: Vec<f64> = (0..1000).map(|i| (i * 37 % 1000) as f64).collect();
+        assert_eq!(
+            recommend_method(&uniform),
+            ClassificationMethod::EqualInterval
+        );
+
+        let power_law: Vec<f64> = (1..=1000).map(|i| 1e6 / (i as f64).powf(1.5)).collect();
+        assert_eq!(
+            recommend_method(&power_law),
+            ClassificationMethod::Logarithmic
+        );
+        let shifted: Vec<f64> = power_law.iter().map(|val| val - 100.0).collect();
+        assert_eq!(recommend_method(&shifted), ClassificationMethod::HeadTail);
+
+        let triangular: Vec<f64> = (0..1000).map(|i| (i % 10 + i % 7) as f64).collect();
+        assert_eq!(recommend_method(&triangular), ClassificationMethod::Jenks);
+
+        let empty: Vec<f64> = vec![];
+        assert_eq!(recommend_method(&empty), ClassificationMethod::Jenks);
+    }
+
+    #[test]
+    fn test_tables() {
+        let class: Classification = vec![
+            Bin {
+                bin_start: 0.0,
+                bin_end: 10.0,
+                count: 0,
+            },
+            Bin {
+                bin_start: 10.0,
+                bin_end: 1000.0,
+                count: 12,
+            },
+        ];
+
+        let markdown = class.to_markdown_table();
+        assert_eq!(markdown.lines().count(), 4);
+        assert_eq!(
+            markdown.lines().nth(2),
+            Some("| 0 | 0.00 - 10.00 | 0 | 0.0% |")
+        );
+
+        let ascii = class.to_ascii_table();
+        let lines: Vec<&str> = ascii.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+        assert_eq!(lines[3], "    1  10.00 - 1000.00     12   100.0%");
+
+        let empty: Classification = vec![];
+        assert_eq!(empty.to_ascii_table().lines().count(), 2);
+    }
+
+    #[test]
+    fn test_streaming_inputs() {
+        let data: Vec<f64> = (0..5000)
+            .map(|i| ((i * 37 % 1001) as f64).powf(1.2))
+            .collect();
+
+        assert!(
+            get_jenks_classification_iter(4, data.iter().copied())
+                == get_jenks_classification(4, &data)
+        );
+
+        let exact = get_quantile_classification(5, &data);
+        let streamed = get_quantile_classification_streaming(5, data.iter().copied());
+        assert_eq!(streamed.len(), 5);
+        assert_eq!(streamed[0].bin_start, exact[0].bin_start);
+        assert_eq!(streamed[4].bin_end, exact[4].bin_end);
+        let range = exact[4].bin_end - exact[0].bin_start;
+        for (approx, brk) in streamed.breaks().iter().zip(exact.breaks()) {
+            assert!((approx - brk).abs() < 0.01 * range);
+        }
+        assert_eq!(streamed.iter().map(|bin| bin.count).sum::<u64>(), 5000);
+        for bin in streamed.iter() {
+            assert!((bin.count as f64 - 1000.0).abs() < 50.0);
+        }
+
+        let single = get_quantile_classification_streaming(1, vec![3.0, 1.0, 2.0]);
+        assert_eq!(single.len(), 1);
+        assert_eq!(
+            (single[0].bin_start, single[0].bin_end, single[0].count),
+            (1.0, 3.0, 3)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_streaming_quantile_non_finite() {
+        get_quantile_classification_streaming(3, vec![1.0, f64::NAN, 2.0]);
+    }
+
+    #[test]
+    fn test_approximate_quantile_classification() {
+        let data: Vec<f64> = (0..20_000)
+            .map(|i| ((i * 7919 % 20_011) as f64 / 20_011.0).powf(3.0) * 1000.0)
+            .collect();
+        let exact = get_quantile_classification(7, &data);
+
+        let max_error = |compression: f64| -> f64 {
+            let approx = get_approximate_quantile_classification(7, &data, compression);
+            assert_eq!(approx.len(), 7);
+            assert_eq!(approx.iter().map(|bin| bin.count).sum::<u64>(), 20_000);
+            approx
+                .breaks()
+                .iter()
+                .zip(exact.breaks())
+                .map(|(approx_break, exact_break)| (approx_break - exact_break).abs())
+                .fold(0.0, f64::max)
+        };
+        // Within 2% and 0.02% of the data's range
+        assert!(max_error(20.0) < 20.0);
+        assert!(max_error(500.0) < 0.2);
+
+        assert_eq!(
+            try_get_approximate_quantile_classification(0, &data, 100.0),
+            Err(ClassifyError::ZeroBins)
+        );
+        let empty: Vec<f64> = vec![];
+        assert_eq!(
+            try_get_approximate_quantile_classification(3, &empty, 100.0),
+            Err(ClassifyError::EmptyData)
+        );
+    }
+
+    #[test]
+    fn test_kmeans_config() {
+        let mut data: Vec<f64> = (0..90).map(|i| (i % 30) as f64 * 0.1).collect();
+        data.extend((0..5).map(|i| 50.0 + i as f64));
+        data.extend((0..5).map(|i| 100.0 + i as f64));
+        let (_, ckmeans_ssd) = get_ckmeans_classification_with_ssd(3, &data);
+
+        // Quantile seeding places every centroid in the large cluster and never recovers
+        let (_, quantile) = KMeansConfig::new().classify_with_stats(3, &data).unwrap();
+        assert!(quantile.ssd > 10.0 * ckmeans_ssd);
+
+        let (classification, plus_plus) = KMeansConfig::new()
+            .init(KMeansInit::PlusPlus)
+            .seed(7)
+            .classify_with_stats(3, &data)
+            .unwrap();
+        assert!((plus_plus.ssd - ckmeans_ssd).abs() < 1e-6);
+        assert_eq!(classification.len(), 3);
+        assert_eq!(
+            plus_plus,
+            KMeansConfig::new()
+                .init(KMeansInit::PlusPlus)
+                .seed(7)
+                .classify_with_stats(3, &data)
+                .unwrap()
+                .1
+        );
+
+        let (_, custom) = KMeansConfig::new()
+            .init(KMeansInit::Custom(vec![100.0, 0.0, 50.0, 50.0]))
+            .classify_with_stats(10, &data)
+            .unwrap();
+        assert_eq!(custom.centroids.len(), 3);
+        assert!((custom.ssd - ckmeans_ssd).abs() < 1e-6);
+
+        assert_eq!(
+            KMeansConfig::new()
+                .init(KMeansInit::Custom(vec![]))
+                .classify(3, &data),
+            Err(ClassifyError::ZeroBins)
+        );
+        assert_eq!(
+            KMeansConfig::new()
+                .init(KMeansInit::Custom(vec![f64::NAN]))
+                .classify(3, &data),
+            Err(ClassifyError::NonFinite)
+        );
+        assert_eq!(
+            get_kmeans_classification_with_stats(3, &data, 100).1,
+            quantile
+        );
+    }
+
+    #[test]
+    fn test_jenks_patience() {
+        let data: Vec<f64> = (0..2000)
+            .map(|i| ((i * 37 % 101) as f64).powf(1.3))
+            .collect();
+
+        // Without a patience, the default number of permutations is always run
+        let (fixed, fixed_stats) = JenksConfig::new().classify_with_stats(4, &data).unwrap();
+        assert!(fixed == get_jenks_classification(4, &data));
+        assert_eq!(fixed_stats.permutations, 10000);
+
+        let (_, short) = JenksConfig::new()
+            .patience(20)
+            .classify_with_stats(4, &data)
+            .unwrap();
+        let (_, long) = JenksConfig::new()
+            .patience(2000)
+            .classify_with_stats(4, &data)
+            .unwrap();
+        assert!(short.permutations >= 20);
+        assert!(long.permutations >= 2000);
+        assert!(long.gvf >= short.gvf);
+
+        // An explicit number of permutations caps a patient run
+        let (_, capped) = JenksConfig::new()
+            .patience(1_000_000)
+            .permutations(500)
+            .classify_with_stats(4, &data)
+            .unwrap();
+        assert_eq!(capped.permutations, 500);
+
+        let (_, single) = JenksConfig::new().classify_with_stats(1, &data).unwrap();
+        assert_eq!(single.permutations, 0);
+    }
+
+    #[test]
+    fn test_jenks_exhaustive_matches_fisher_jenks() {
+        let datasets: Vec<Vec<f64>> = vec![
+            (0..200).map(|i| ((i * 7 % 13) as f64).powi(2)).collect(),
+            (0..60)
+                .map(|i| ((i % 20) as f64).powf(1.5) + (i % 20 % 3) as f64)
+                .collect(),
+            vec![1.0, 1.0, 1.0, 2.0, 9.0, 9.5, 10.0, 30.0, 31.0, 31.0, 80.0],
+        ];
+        for data in datasets.iter() {
+            let mut sorted = data.clone();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let gssd = jenks::calc_gssd(&sorted);
+            let to_indices = |breaks: &[f64]| -> Vec<usize> {
+                breaks
+                    .iter()
+                    .map(|brk| sorted.partition_point(|val| val < brk))
+                    .collect()
+            };
+
+            for num_bins in 2..=5 {
+                let (_, stats) = JenksConfig::new()
+                    .permutations(1)
+                    .seed(99)
+                    .classify_with_stats(num_bins, data)
+                    .unwrap();
+                let jenks_breaks = JenksConfig::new()
+                    .permutations(1)
+                    .seed(99)
+                    .breaks(num_bins, data)
+                    .unwrap();
+                assert!(stats.permutations > 1);
+                assert_eq!(jenks_breaks, get_jenks_breaks(num_bins, data));
+
+                let fisher_breaks = get_fisher_jenks_breaks(num_bins, data);
+                let jenks_gvf = jenks::calc_gvf(&to_indices(&jenks_breaks), &sorted, &gssd);
+                let fisher_gvf = jenks::calc_gvf(&to_indices(&fisher_breaks), &sorted, &gssd);
+                assert!((jenks_gvf - fisher_gvf).abs() < 1e-9);
+                assert_eq!(jenks_breaks, fisher_breaks);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tailed_quantile_classification() {
+        let data: Vec<f64> = (0..1000)
+            .map(|i| ((i * 37 % 1000) as f64).powf(2.0))
+            .collect();
+
+        let (result, (lower, upper)) = get_tailed_quantile_classification(4, &data, 0.1);
+        assert_eq!(result.len(), 6);
+        assert_eq!((result[0].bin_end, result[5].bin_start), (lower, upper));
+        let counts: Vec<u64> = result.iter().map(|bin| bin.count).collect();
+        assert_eq!(counts, vec![100, 200, 200, 200, 200, 100]);
+
+        // Without tails, the thresholds are the data's range
+        let (untailed, (min, max)) = get_tailed_quantile_classification(4, &data, 0.0);
+        assert_eq!(untailed.len(), 4);
+        assert_eq!((min, max), (0.0, 999.0 * 999.0));
+
+        assert_eq!(
+            try_get_tailed_quantile_classification(0, &data, 0.1),
+            Err(ClassifyError::ZeroBins)
+        );
+        assert_eq!(
+            try_get_tailed_quantile_classification(2, &data, f64::NAN),
+            Err(ClassifyError::PercentileOutOfRange)
+        );
+        assert_eq!(
+            try_get_tailed_quantile_classification(2, &[1.0, f64::INFINITY], 0.1),
+            Err(ClassifyError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_parse_breaks() {
+        assert_eq!(
+            parse_breaks("0,10,25,50,100"),
+            Ok(vec![0.0, 10.0, 25.0, 50.0, 100.0])
+        );
+        assert_eq!(parse_breaks("\t-1.5 ,  2e3\n"), Ok(vec![-1.5, 2000.0]));
+        assert_eq!(parse_breaks("   "), Ok(vec![]));
+
+        for spec in ["1,,2", "1,2,", "1;2", "abc", "1, NaN", "1, inf"] {
+            assert_eq!(parse_breaks(spec), Err(ClassifyError::MalformedBreaks));
+        }
+        assert_eq!(parse_breaks("1, 3, 2"), Err(ClassifyError::UnsortedBreaks));
+        assert_eq!(parse_breaks("2, 2"), Err(ClassifyError::UnsortedBreaks));
+
+        let data: Vec<f64> = vec![1.0, 4.0, 6.0, 9.0];
+        let class = get_manual_classification(&parse_breaks("5").unwrap(), &data).unwrap();
+        assert_eq!(
+            class.iter().map(|bin| bin.count).collect::<Vec<u64>>(),
+            vec![2, 2]
+        );
+    }
+
+    #[test]
+    fn test_reversed_classification() {
+        let data: Vec<f64> = (0..50).map(|i| ((i * 13 % 50) as f64).powf(1.4)).collect();
+        let class = get_equal_interval_classification(5, &data);
+        let reversed = class.reversed();
+
+        assert_eq!(reversed.len(), 5);
+        assert!(reversed.iter().all(|bin| bin.bin_start < bin.bin_end));
+        assert!(reversed.reversed() == class);
+
+        // Each value falls into the same bin, found at its mirrored index
+        let last = class.len() - 1;
+        for val in data.iter().chain([-5.0, 1e6].iter()) {
+            assert_eq!(
+                reversed.classify(*val),
+                class.classify(*val).map(|idx| last - idx)
+            );
+            assert_eq!(
+                reversed.classify_clamped(*val),
+                class.classify_clamped(*val).map(|idx| last - idx)
+            );
+        }
+        let max = data.iter().cloned().fold(f64::MIN, f64::max);
+        assert_eq!(reversed.classify(max), Some(0));
+        assert_eq!(reversed.classify_clamped(-5.0), Some(last));
+        assert_eq!(reversed.assign_all(&[max, 0.0], None), vec![0, last]);
+
+        let single = get_equal_interval_classification(1, &data);
+        assert!(single.reversed() == single);
+    }
+
+    #[test]
+    fn test_jenks_more_bins_than_values() {
+        let data: Vec<f64> = vec![7.0, 1.0, 4.0, 2.0];
+        let expected: Classification = vec![
+            Bin {
+                bin_start: 1.0,
+                bin_end: 2.0,
+                count: 1,
+            },
+            Bin {
+                bin_start: 2.0,
+                bin_end: 4.0,
+                count: 1,
+            },
+            Bin {
+                bin_start: 4.0,
+                bin_end: 7.0,
+                count: 1,
+            },
+            Bin {
+                bin_start: 7.0,
+                bin_end: 7.0,
+                count: 1,
+            },
+        ];
+        for num_bins in [data.len(), 10] {
+            assert!(get_jenks_classification(num_bins, &data) == expected);
+            let result = ClassificationMethod::Jenks
+                .classify_with_result(num_bins, &data)
+                .unwrap();
+            assert_eq!(result.requested_bins, num_bins);
+            assert_eq!(result.actual_bins, 4);
+        }
+
+        // Repeated values cap the number of bins at the number of unique values, each bin keeping its data points
+        let repeated: Vec<f64> = vec![3.0, 1.0, 3.0, 1.0, 2.0, 3.0];
+        for num_bins in [repeated.len(), 10] {
+            let result = ClassificationMethod::Jenks
+                .classify_with_result(num_bins, &repeated)
+                .unwrap();
+            assert_eq!(result.actual_bins, 3);
+            let counts: Vec<u64> = result.classification.iter().map(|bin| bin.count).collect();
+            assert_eq!(counts, vec![2, 1, 3]);
+        }
+    }
+
+    #[test]
+    fn test_jenks_breaks_strictly_increasing() {
+        // One value repeated far more often than all others together
+        let mut data: Vec<f64> = vec![5.0; 2000];
+        data.extend([1.0, 2.0, 9.0, 12.0, 30.0]);
+        for num_bins in 2..8 {
+            let breaks = get_jenks_breaks(num_bins, &data);
+            assert!(breaks.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(breaks.iter().all(|brk| *brk > 1.0));
+
+            let result = ClassificationMethod::Jenks
+                .classify_with_result(num_bins, &data)
+                .unwrap();
+            assert_eq!(result.actual_bins, breaks.len() + 1);
+            assert!(result.classification.iter().all(|bin| bin.count > 0));
+            assert!(result.classification.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_silhouette_score_1d() {
+        // Three tight clusters far apart
+        let separated: Vec<f64> = (0..90)
+            .map(|i| (i / 30) as f64 * 100.0 + (i * 7 % 11) as f64)
+            .collect();
+        // Evenly spread values with no natural groups
+        let spread: Vec<f64> = (0..90).map(|i| i as f64 * 3.0).collect();
+
+        let separated_score =
+            silhouette_score_1d(&get_jenks_classification(3, &separated), &separated);
+        let spread_score = silhouette_score_1d(&get_jenks_classification(3, &spread), &spread);
+        assert!(separated_score > 0.9);
+        assert!(spread_score < separated_score - 0.2);
+        assert!((-1.0..=1.0).contains(&spread_score));
+
+        // Splitting the clusters further or merging two of them both lower the score
+        let split_score = silhouette_score_1d(&get_jenks_classification(6, &separated), &separated);
+        let merged_score = silhouette_score_1d(
+            &get_manual_classification(&[150.0], &separated).unwrap(),
+            &separated,
+        );
+        assert!(split_score < separated_score);
+        assert!(merged_score < separated_score);
+
+        assert!(silhouette_score_1d(&get_jenks_classification(1, &separated), &separated).is_nan());
+    }
+
+    #[test]
+    fn test_calinski_harabasz_1d() {
+        // Three tight clusters far apart
+        let data: Vec<f64> = vec![9.0, 10.0, 11.0, 49.0, 50.0, 51.0, 89.0, 90.0, 91.0];
+
+        let scores: Vec<f64> = (2..=8)
+            .map(|num_bins| calinski_harabasz_1d(&get_jenks_classification(num_bins, &data), &data))
+            .collect();
+        let best = scores.iter().enumerate().fold(
+            0,
+            |best, (i, score)| if *score > scores[best] { i } else { best },
+        );
+        assert_eq!(best + 2, 3);
+
+        assert!(calinski_harabasz_1d(&get_jenks_classification(1, &data), &data).is_nan());
+        let pairs: Vec<f64> = vec![1.0, 1.0, 5.0, 5.0];
+        assert_eq!(
+            calinski_harabasz_1d(&get_jenks_classification(2, &pairs), &pairs),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_davies_bouldin_1d() {
+        // Three tight clusters far apart
+        let data: Vec<f64> = vec![9.0, 10.0, 11.0, 49.0, 50.0, 51.0, 89.0, 90.0, 91.0];
+
+        let scores: Vec<f64> = (2..=8)
+            .map(|num_bins| davies_bouldin_1d(&get_jenks_classification(num_bins, &data), &data))
+            .collect();
+        let best = scores.iter().enumerate().fold(
+            0,
+            |best, (i, score)| if *score < scores[best] { i } else { best },
+        );
+        assert_eq!(best + 2, 3);
+
+        // Classes holding a single data point have no spread
+        let singles: Vec<f64> = vec![1.0, 5.0, 6.0, 7.0, 20.0];
+        let classification = get_manual_classification(&[5.0, 20.0], &singles).unwrap();
+        let index = davies_bouldin_1d(&classification, &singles);
+        let spread = 2.0 / 3.0;
+        assert!((index - (spread / 5.0 + spread / 5.0 + spread / 14.0) / 3.0).abs() < 1e-12);
+
+        assert!(davies_bouldin_1d(&get_jenks_classification(1, &data), &data).is_nan());
+    }
+
+    #[test]
+    fn test_suggest_num_bins_elbow() {
+        // Three groups of values, each spread over a few units
+        let data: Vec<f64> = (0..90)
+            .map(|i| (i % 3) as f64 * 50.0 + (i * 7 % 13) as f64 * 0.5)
+            .collect();
+        let (num_bins, gvfs) = suggest_num_bins_elbow(&data, 10);
+        assert_eq!(num_bins, 3);
+        assert_eq!(gvfs.len(), 10);
+        assert_eq!(gvfs[0], 0.0);
+        assert!(gvfs.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(
+            gvfs[3],
+            goodness_of_variance_fit(&get_fisher_jenks_classification(4, &data), &data)
+        );
+
+        // The curve stops at the number of unique values
+        let few: Vec<f64> = vec![1.0, 1.0, 5.0, 9.0];
+        let (_, gvfs) = suggest_num_bins_elbow(&few, 10);
+        assert_eq!(gvfs.len(), 3);
+        assert_eq!(suggest_num_bins_elbow(&[3.0, 3.0], 5), (1, vec![0.0]));
+        assert_eq!(suggest_num_bins_elbow(&[1.0, 2.0], 5).0, 1);
+    }
+
+    #[test]
+    fn test_per_class_absolute_deviation() {
+        let classification: Classification = vec![
+            Bin {
+                bin_start: 0.0,
+                bin_end: 10.0,
+                count: 4,
+            },
+            Bin {
+                bin_start: 10.0,
+                bin_end: 20.0,
+                count: 0,
+            },
+            Bin {
+                bin_start: 20.0,
+                bin_end: 30.0,
+                count: 3,
+            },
+        ];
+        // The first bin's median is 2.5 (so |1 - 2.5| + |2 - 2.5| + |3 - 2.5| + |9 - 2.5| = 9) and the last bin's is 21 (so 1 + 0 + 9 = 10)
+        let data: Vec<f64> = vec![9.0, 1.0, 3.0, 2.0, 30.0, 20.0, 21.0, 40.0];
+        let deviations = per_class_absolute_deviation(&classification, &data);
+        assert_eq!(deviations, vec![9.0, 0.0, 10.0]);
+
+        // The per-class sums make up the numerator of the GADF
+        let class = get_jenks_classification(3, &data[..7]);
+        let total: f64 = per_class_absolute_deviation(&class, &data[..7])
+            .iter()
+            .sum();
+        let global_median = 9.0;
+        let total_dev: f64 = data[..7]
+            .iter()
+            .map(|val| (val - global_median).abs())
+            .sum();
+        assert_eq!(
+            goodness_of_absolute_deviation_fit(&class, &data[..7]),
+            1.0 - total / total_dev
+        );
+    }
+
+    #[test]
+    fn test_classify_with_result_bins_reduced() {
+        // Only three unique values, each repeated many times
+        let data: Vec<f64> = (0..300).map(|i| (i % 3) as f64 * 10.0).collect();
+
+        let result = ClassificationMethod::Jenks
+            .classify_with_result(5, &data)
+            .unwrap();
+        assert_eq!(result.actual_bins, 3);
+        assert_eq!(
+            result.warnings,
+            vec![Warning::BinsReduced {
+                requested: 5,
+                actual: 3
+            }]
+        );
+
+        let mut with_nan = data.clone();
+        with_nan.push(f64::NAN);
+        let result = ClassificationMethod::FisherJenks
+            .classify_with_result(4, &with_nan)
+            .unwrap();
+        assert_eq!(
+            result.warnings,
+            vec![
+                Warning::DroppedNonFinite { count: 1 },
+                Warning::BinsReduced {
+                    requested: 4,
+                    actual: 3
+                }
+            ]
+        );
+
+        // No warning when the requested number of bins is produced, or when the method ignores it
+        let result = ClassificationMethod::Jenks
+            .classify_with_result(3, &data)
+            .unwrap();
+        assert!(result.warnings.is_empty());
+        let result = ClassificationMethod::Manual(vec![5.0])
+            .classify_with_result(5, &data)
+            .unwrap();
+        assert_eq!(result.actual_bins, 2);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_harmonic_interval() {
+        let data: Vec<f64> = vec![0.5, 0.8, 1.0, 2.0, 3.5, 5.0, 8.0, 10.0];
+        let breaks = get_harmonic_interval_breaks(4, &data).unwrap();
+        assert_eq!(breaks.len(), 3);
+        assert!(breaks.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // The reciprocals of the bounds are evenly spaced
+        let mut reciprocals: Vec<f64> = vec![1.0 / 0.5];
+        reciprocals.extend(breaks.iter().map(|brk| 1.0 / brk));
+        reciprocals.push(1.0 / 10.0);
+        for pair in reciprocals.windows(2) {
+            assert!((pair[0] - pair[1] - (2.0 - 0.1) / 4.0).abs() < 1e-12);
+        }
+
+        let class = get_harmonic_interval_classification(4, &data).unwrap();
+        assert!(class == breaks_to_classification(&breaks, &data));
+
+        assert_eq!(
+            get_harmonic_interval_breaks(3, &[2.0, -1.0]),
+            Err(ClassifyError::NonPositiveForHarmonic)
+        );
+        assert_eq!(
+            get_harmonic_interval_breaks(0, &data),
+            Err(ClassifyError::ZeroBins)
+        );
+        assert_eq!(
+            get_harmonic_interval_classification(3, &[4.0, 4.0])
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_sqrt_interval() {
+        let data: Vec<f64> = vec![2.0, 3.0, 10.0, 40.0, 75.0, 120.0, 200.0];
+        let breaks = get_sqrt_interval_breaks(4, &data).unwrap();
+        assert_eq!(breaks.len(), 3);
+
+        // The square roots of the bounds are evenly spaced
+        let mut roots: Vec<f64> = vec![2.0_f64.sqrt()];
+        roots.extend(breaks.iter().map(|brk| brk.sqrt()));
+        roots.push(200.0_f64.sqrt());
+        let step = (200.0_f64.sqrt() - 2.0_f64.sqrt()) / 4.0;
+        for pair in roots.windows(2) {
+            assert!((pair[1] - pair[0] - step).abs() < 1e-12);
+        }
+        let class = get_sqrt_interval_classification(4, &data).unwrap();
+        assert!(class == breaks_to_classification(&breaks, &data));
+
+        // Zeros are allowed, while negative values are rejected
+        assert_eq!(
+            get_sqrt_interval_breaks(2, &[0.0, 0.0, 9.0]),
+            Ok(vec![2.25])
+        );
+        assert_eq!(
+            get_sqrt_interval_breaks(2, &[0.0, -0.5]),
+            Err(ClassifyError::NegativeForSqrt)
+        );
+        assert_eq!(
+            get_sqrt_interval_classification(3, &[0.0, 0.0])
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_top_n_unique_classification() {
+        // 7 and 2 tie for the second highest frequency, so the smaller value is kept
+        let data: Vec<f64> = vec![7.0, 5.0, 2.0, 5.0, 9.0, 7.0, 5.0, 2.0, 1.0, 5.0];
+        let (class, other_vals) = get_top_n_unique_classification(2, &data);
+        let expected: Classification = vec![
+            Bin {
+                bin_start: 2.0,
+                bin_end: 2.0,
+                count: 2,
+            },
+            Bin {
+                bin_start: 5.0,
+                bin_end: 5.0,
+                count: 4,
+            },
+            Bin {
+                bin_start: 1.0,
+                bin_end: 9.0,
+                count: 4,
+            },
+        ];
+        assert!(class == expected);
+        assert_eq!(other_vals, vec![1.0, 7.0, 9.0]);
+
+        // Matches the capped unique value classification, which counts the "other" bin as a class
+        assert!(class == get_unique_value_classification(&data, Some(3)));
+
+        let (class, other_vals) = get_top_n_unique_classification(10, &data);
+        assert!(class == get_unique_value_classification(&data, None));
+        assert!(other_vals.is_empty());
+
+        let (class, other_vals) = get_top_n_unique_classification(0, &data);
+        assert_eq!(class.len(), 1);
+        assert_eq!(class[0].count, data.len() as u64);
+        assert_eq!(other_vals, vec![1.0, 2.0, 5.0, 7.0, 9.0]);
+    }
+
+    #[test]
+    fn test_head_tail_constant_inexact_mean() {
+        // The float mean of these values is not exactly 0.1
+        for len in [3, 7] {
+            let data: Vec<f64> = vec![0.1; len];
+            assert!(get_head_tail_breaks(&data).is_empty());
+            let class = get_head_tail_classification(&data);
+            assert_eq!(class.len(), 1);
+            assert_eq!(class[0].count, len as u64);
+        }
+    }
+
+    #[test]
+    fn test_data_summary_std_dev() {
+        let data: Vec<f64> = vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0];
+        assert!(
+            (DataSummary::from_data(&data).std_dev() - standard_deviation::calc_st_dev(&data))
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn test_data_summary_nan_order() {
+        for data in [
+            vec![f64::NAN, 1.0, 2.0],
+            vec![1.0, f64::NAN, 2.0],
+            vec![1.0, 2.0, f64::NAN],
+        ] {
+            let summary = DataSummary::from_data(&data);
+            assert_eq!(summary.count, 3);
+            assert!(summary.min.is_nan(), "{:?}", data);
+            assert!(summary.max.is_nan(), "{:?}", data);
+            assert!(summary.mean.is_nan() && summary.variance.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_jenks_large_offset() {
+        // Three groups of small, exactly representable values, and the same values shifted by 1e9
+        let data: Vec<f64> = (0..60)
+            .map(|i| (i / 20) as f64 * 2.0 + (i * 7 % 20) as f64 * 0.03125)
+            .collect();
+        let shifted: Vec<f64> = data.iter().map(|val| val + 1e9).collect();
+
+        let breaks = get_jenks_breaks(3, &data);
+        let shifted_breaks = get_jenks_breaks(3, &shifted);
+        assert_eq!(breaks, vec![2.0, 4.0]);
+        let unshifted: Vec<f64> = shifted_breaks.iter().map(|brk| brk - 1e9).collect();
+        assert_eq!(unshifted, breaks);
+
+        // The segment sums of squared deviations match a direct calculation on the unshifted values
+        let mut sorted = shifted.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut sorted_data = data.clone();
+        sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let sums = jenks::PrefixSums::new(&sorted);
+        for (lower, upper) in [(0, 20), (20, 40), (5, 55), (0, 60)] {
+            let direct = jenks::calc_ssd(&sorted_data[lower..upper]);
+            assert!((sums.ssd(lower, upper) - direct).abs() < 1e-6 * direct.max(1.0));
+        }
+    }
+
+    #[test]
+    fn test_logarithmic_invalid_input() {
+        let empty: Vec<f64> = vec![];
+        assert_eq!(
+            get_logarithmic_breaks(3, &empty),
+            Err(ClassifyError::EmptyData)
+        );
+        assert_eq!(
+            get_logarithmic_classification(3, &empty),
+            Err(ClassifyError::EmptyData)
+        );
+        assert_eq!(
+            get_logarithmic_breaks(3, &[1.0, f64::NAN, 10.0]),
+            Err(ClassifyError::NonFinite)
+        );
+        assert_eq!(
+            get_logarithmic_breaks(0, &[1.0, 10.0]),
+            Err(ClassifyError::ZeroBins)
+        );
+    }
+
+    #[test]
+    fn test_jenks_seed_matches_across_paths() {
+        let mut rng = StdRng::seed_from_u64(21);
+        let data: Vec<f64> = (0..500).map(|_| rng.gen_range(0.0..100.0)).collect();
+        let config = JenksConfig::new().permutations(300).seed(4);
+
+        // A patience that never runs out takes the serial path even with the "rayon" feature
+        let unbounded = config.clone().breaks(5, &data).unwrap();
+        let patient = config.patience(300).breaks(5, &data).unwrap();
+        assert_eq!(unbounded, patient);
+    }
+
+    #[test]
+    fn test_try_wrappers_degenerate_data() {
+        // A standard deviation that underflows to zero places no breaks instead of overflowing
+        let subnormal: Vec<f64> = vec![-1e-300, 5e-324, 0.0];
+        assert_eq!(
+            try_get_st_dev_classification(0.5, &subnormal)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // Steps below the spacing of large values stop instead of looping forever
+        let offset: Vec<f64> = (0..50).map(|i| 1e15 + i as f64 * 0.1).collect();
+        let pretty = try_get_pretty_classification(100, &offset).unwrap();
+        assert_eq!(pretty.iter().map(|bin| bin.count).sum::<u64>(), 50);
+
+        let constant: Vec<f64> = vec![0.1; 7];
+        assert_eq!(
+            try_get_head_tail_classification(&constant).unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_suggest_bin_count_non_finite() {
+        let data: Vec<f64> = vec![1.0, f64::NAN, 3.0, 4.0, f64::INFINITY];
+        assert_eq!(suggest_bin_count(&data, BinCountRule::FreedmanDiaconis), 1);
+        assert_eq!(suggest_bin_count(&data, BinCountRule::Scott), 1);
+        assert_eq!(suggest_bin_count(&data, BinCountRule::SquareRoot), 3);
+    }
+
+    #[test]
+    fn test_percentile_of_non_finite() {
+        let data: Vec<f64> = vec![4.0, f64::NAN, 1.0, 3.0, f64::INFINITY, 2.0];
+        assert_eq!(percentile_of(&data, 50.0), 2.5);
+        assert_eq!(empirical_cdf(&data, 2.0), 0.5);
+        assert!(percentile_of(&[f64::NAN], 50.0).is_nan());
+        assert!(empirical_cdf(&[f64::NEG_INFINITY], 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_create_unique_val_mapping_repeated_last() {
+        let mut unique_val_map: Vec<UniqueVal> = vec![];
+        let data: Vec<f64> = vec![2.0, 2.0, 7.0, 7.0, 7.0, 8.0, 8.0];
+
+        utilities::create_unique_val_mapping(&mut unique_val_map, &data);
+
+        let ranges: Vec<(f64, usize, usize)> = unique_val_map
+            .iter()
+            .map(|unique| (unique.val, unique.first, unique.last))
+            .collect();
+        assert_eq!(ranges, vec![(2.0, 0, 1), (7.0, 2, 4), (8.0, 5, 6)]);
+    }
+
+    #[test]
+    fn test_defined_interval_invalid_input() {
+        let empty: Vec<f64> = vec![];
+        assert_eq!(
+            get_defined_interval_breaks(1.0, &empty),
+            Err(ClassifyError::EmptyData)
+        );
+        assert_eq!(
+            get_defined_interval_classification(1.0, &[0.0, f64::INFINITY]),
+            Err(ClassifyError::NonFinite)
+        );
+        assert_eq!(
+            get_defined_interval_breaks(1e-300, &[0.0, 1.0, 3.0]),
+            Err(ClassifyError::TooManyBins)
+        );
+        assert_eq!(
+            get_defined_interval_breaks(1.0, &[f64::MIN, f64::MAX]),
+            Err(ClassifyError::TooManyBins)
+        );
+        assert_eq!(
+            get_defined_interval_breaks(1.0, &[0.0, 3.0]),
+            Ok(vec![1.0, 2.0])
+        );
+    }
+
+    #[test]
+    fn test_percentile_and_manual_invalid_data() {
+        let empty: Vec<f64> = vec![];
+        let with_nan: Vec<f64> = vec![1.0, f64::NAN, 3.0];
+        let linear = PercentileInterpolation::Linear;
+
+        assert_eq!(
+            get_percentile_breaks(&[50.0], &empty, linear),
+            Err(ClassifyError::EmptyData)
+        );
+        assert_eq!(
+            get_percentile_classification(&[50.0], &with_nan, linear),
+            Err(ClassifyError::NonFinite)
+        );
+        assert_eq!(
+            get_manual_classification(&[2.0], &empty),
+            Err(ClassifyError::EmptyData)
+        );
+        assert_eq!(
+            get_manual_classification(&[2.0], &with_nan),
+            Err(ClassifyError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_st_dev_tiny_bin_size() {
+        let data: Vec<f64> = vec![0.0, 1.0, 2.0, 3.0];
+        assert_eq!(
+            try_get_st_dev_classification(1e-9, &data),
+            Err(ClassifyError::TooManyBins)
+        );
+        // Small bin sizes within the limit still produce bins, including empty ones
+        assert_eq!(
+            try_get_st_dev_classification(0.1, &data).unwrap().len(),
+            get_st_dev_classification(0.1, &data).len()
+        );
+    }
 }
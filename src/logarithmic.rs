@@ -0,0 +1,112 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+use num_traits::ToPrimitive;
+
+/// Returns a Classification object following the Logarithmic Interval Breaks algorithm given the desired number of bins and one-dimensional data
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns errors in the same cases as get_logarithmic_breaks
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_logarithmic_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<u32> = vec![1, 5, 10, 50, 100, 500, 1000];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_logarithmic_classification(num_bins, &data).unwrap();
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 10.0, count: 2},
+///     Bin{bin_start: 10.0, bin_end: 100.0, count: 2},
+///     Bin{bin_start: 100.0, bin_end: 1000.0, count: 3}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_logarithmic_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    let breaks: Vec<f64> = get_logarithmic_breaks(num_bins, data)?;
+    Ok(breaks_to_classification(&breaks, data))
+}
+
+/// Returns a vector of breaks spaced equally in log space given the desired number of bins and a dataset
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+/// * Returns `ClassifyError::NonPositiveForLog` if any data point is less than or equal to zero (including exactly zero, whose logarithm is undefined)
+/// * If all data points share the same value, no breaks are generated
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_logarithmic_breaks;
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 5.0, 10.0, 50.0, 100.0, 500.0, 1000.0];
+///
+/// assert_eq!(get_logarithmic_breaks(3, &data), Ok(vec![10.0, 100.0]));
+/// assert_eq!(get_logarithmic_breaks(3, &[0.0, 1.0]), Err(ClassifyError::NonPositiveForLog));
+/// ```
+pub fn get_logarithmic_breaks<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Vec<f64>, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    let data = to_vec_f64(data);
+
+    let mut min_value = data[0];
+    let mut max_value = data[0];
+    for item in &data {
+        if *item <= 0.0 {
+            return Err(ClassifyError::NonPositiveForLog);
+        }
+        if *item < min_value {
+            min_value = *item;
+        } else if *item > max_value {
+            max_value = *item;
+        }
+    }
+
+    let mut result: Vec<f64> = vec![];
+    if min_value == max_value {
+        return Ok(result);
+    }
+
+    let log_min = min_value.log10();
+    let log_max = max_value.log10();
+    for i in 1..num_bins {
+        let log_break = log_min + (log_max - log_min) * (i as f64 / num_bins as f64);
+        result.push(10_f64.powf(log_break));
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,111 @@
+use alloc::vec::Vec;
+
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, validate_data};
+use num_traits::ToPrimitive;
+
+use core::cmp::Ordering;
+
+/// Returns a Classification object given a set of user-supplied breaks and one-dimensional data, after checking that the breaks are strictly increasing
+///
+/// # Arguments
+///
+/// * `breaks` - A reference to a collection of breaks (f64) between bins, which should lie within the range of the data
+/// * `data` - A reference to a collection of unsorted data points to count into the bins
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * A value lying exactly on a break is counted in the bin to the right of (above) the break
+/// * Returns `ClassifyError::UnsortedBreaks` if the breaks are unsorted or contain duplicates
+/// * Returns `ClassifyError::EmptyData` if data contains no values and `ClassifyError::NonFinite` if it contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_manual_classification;
+/// use classify::{Classification, Bin, ClassifyError};
+///
+/// let data: Vec<u32> = vec![1, 2, 4, 5, 7, 8];
+///
+/// let result: Classification = get_manual_classification(&[3.0, 5.0], &data).unwrap();
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 3.0, count: 2},
+///     Bin{bin_start: 3.0, bin_end: 5.0, count: 1},
+///     Bin{bin_start: 5.0, bin_end: 8.0, count: 3}
+/// ];
+///
+/// assert!(result == expected);
+/// assert_eq!(get_manual_classification(&[5.0, 3.0], &data), Err(ClassifyError::UnsortedBreaks));
+/// ```
+pub fn get_manual_classification<T: ToPrimitive>(
+    breaks: &[f64],
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_breaks(breaks)?;
+    validate_data(data)?;
+
+    Ok(breaks_to_classification(breaks, data))
+}
+
+/// Parses a textual list of breaks separated by commas, such as "0, 10, 25, 50, 100" from a command line or configuration file, for use with get_manual_classification
+///
+/// # Arguments
+///
+/// * `spec` - The breaks as numbers separated by commas, with any whitespace around them ignored
+///
+/// # Edge cases
+///
+/// * An empty (or whitespace-only) spec yields no breaks, which produces a single bin
+/// * Returns `ClassifyError::MalformedBreaks` if a token is empty (as with "1,,2" or a trailing comma) or is not a finite number
+/// * Returns `ClassifyError::UnsortedBreaks` if the breaks are not strictly increasing
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_manual_classification, parse_breaks};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+///
+/// let breaks: Vec<f64> = parse_breaks(" 3, 5.5 ").unwrap();
+/// assert_eq!(breaks, vec![3.0, 5.5]);
+/// assert_eq!(get_manual_classification(&breaks, &data).unwrap().len(), 3);
+///
+/// assert_eq!(parse_breaks("3, five"), Err(ClassifyError::MalformedBreaks));
+/// assert_eq!(parse_breaks("5, 3"), Err(ClassifyError::UnsortedBreaks));
+/// ```
+pub fn parse_breaks(spec: &str) -> Result<Vec<f64>, ClassifyError> {
+    if spec.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut breaks: Vec<f64> = Vec::new();
+    for token in spec.split(',') {
+        match token.trim().parse::<f64>() {
+            Ok(brk) if brk.is_finite() => breaks.push(brk),
+            _ => return Err(ClassifyError::MalformedBreaks),
+        }
+    }
+    validate_breaks(&breaks)?;
+
+    Ok(breaks)
+}
+
+/// Checks that a collection of breaks is strictly increasing (which also rejects NaN breaks)
+///
+/// # Arguments
+///
+/// * `breaks` - A reference to a collection of breaks (f64) to validate
+pub fn validate_breaks(breaks: &[f64]) -> Result<(), ClassifyError> {
+    if breaks.iter().any(|brk| brk.is_nan()) {
+        return Err(ClassifyError::UnsortedBreaks);
+    }
+    for i in 1..breaks.len() {
+        if breaks[i - 1].partial_cmp(&breaks[i]) != Some(Ordering::Less) {
+            return Err(ClassifyError::UnsortedBreaks);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use alloc::{vec, vec::Vec};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+use num_traits::ToPrimitive;
+
+use crate::error::ClassifyError;
+use crate::fisher_jenks::optimal_unique_breaks;
+use crate::utilities::{breaks_to_classification, create_unique_val_mapping, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+use crate::utilities::{Classification, UniqueVal};
+
+/// Returns a Classification object following the Maximum Entropy algorithm given the desired number of bins and one-dimensional data
+///
+/// The breaks maximize the entropy -Σ p_i ln(p_i) of the shares p_i of data points in each bin, which favors bins with equal counts. Unlike get_quantile_classification, breaks are only placed between unique values, so the result is exact even when ties prevent bins from having equal counts
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Run time grows with the square of the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_max_entropy_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f64> = vec![1.0, 1.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let num_bins = 2;
+///
+/// let result: Classification = get_max_entropy_classification(num_bins, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 3.0, count: 4},
+///     Bin{bin_start: 3.0, bin_end: 6.0, count: 4}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_max_entropy_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Classification {
+    get_max_entropy_classification_with_entropy(num_bins, data).0
+}
+
+/// Returns a Classification object following the Maximum Entropy algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_max_entropy_classification, try_get_max_entropy_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_max_entropy_classification(3, &data).unwrap() == get_max_entropy_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_max_entropy_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_max_entropy_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_max_entropy_classification(num_bins, data))
+}
+
+/// Returns a Classification object following the Maximum Entropy algorithm along with the entropy (in nats, using the natural logarithm) of the shares of data points in its bins
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The entropy is at most ln(num_bins), which is reached when every bin holds the same number of data points
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_max_entropy_classification_with_entropy;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+///
+/// let (result, entropy) = get_max_entropy_classification_with_entropy(3, &data);
+///
+/// assert_eq!(result.len(), 3);
+/// assert!((entropy - 3.0_f64.ln()).abs() < 1e-12);
+/// ```
+pub fn get_max_entropy_classification_with_entropy<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> (Classification, f64) {
+    let breaks: Vec<f64> = get_max_entropy_breaks(num_bins, data);
+    let classification = breaks_to_classification(&breaks, data);
+
+    let num_vals = data.len() as f64;
+    let entropy = classification
+        .iter()
+        .filter(|bin| bin.count > 0)
+        .map(|bin| {
+            let share = bin.count as f64 / num_vals;
+            -share * share.ln()
+        })
+        .sum();
+
+    (classification, entropy)
+}
+
+/// Returns a vector of breaks generated through the Maximum Entropy algorithm given the desired number of bins and a dataset
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * Run time grows with the square of the number of unique values in the dataset
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_max_entropy_breaks;
+///
+/// let data: Vec<u32> = vec![1, 1, 1, 2, 3, 4, 5, 6];
+///
+/// let result: Vec<f64> = get_max_entropy_breaks(2, &data);
+///
+/// assert_eq!(result, vec![3.0]);
+/// ```
+pub fn get_max_entropy_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    let num_unique_vals = unique_val_map.len();
+    let true_num_bins = core::cmp::min(num_unique_vals, num_bins);
+    if true_num_bins <= 1 {
+        return vec![];
+    }
+
+    // cum_counts[j] holds the number of data points among the first j unique values
+    let mut cum_counts: Vec<usize> = vec![0];
+    for item in &unique_val_map {
+        cum_counts.push(cum_counts[cum_counts.len() - 1] + item.last - item.first + 1);
+    }
+    let num_vals = sorted_data.len() as f64;
+
+    // Minimizing the sum of p * ln(p) over the bins maximizes their entropy
+    let unique_breaks = optimal_unique_breaks(true_num_bins, num_unique_vals, |start, end| {
+        let share = (cum_counts[end] - cum_counts[start]) as f64 / num_vals;
+        share * share.ln()
+    });
+
+    let mut breaks: Vec<f64> = vec![];
+    for idx in unique_breaks {
+        breaks.push(unique_val_map[idx].val);
+    }
+    breaks
+}
@@ -0,0 +1,191 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::ToPrimitive;
+
+use crate::error::ClassifyError;
+use crate::utilities::{breaks_to_classification, create_unique_val_mapping, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+use crate::utilities::{Classification, UniqueVal};
+
+/// Returns a Classification object following the Maximum Breaks algorithm given the desired number of bins and one-dimensional data
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * If several gaps are equally wide, the gaps between lower values are used first
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_maximum_breaks_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f32> = vec![1.0, 2.0, 6.0, 7.0, 8.0, 15.0, 16.0];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_maximum_breaks_classification(num_bins, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 11.5, count: 3},
+///     Bin{bin_start: 11.5, bin_end: 16.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_maximum_breaks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Classification {
+    let breaks: Vec<f64> = get_maximum_breaks(num_bins, data);
+    breaks_to_classification(&breaks, data)
+}
+
+/// Returns a Classification object following the Maximum Breaks algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_maximum_breaks_classification, try_get_maximum_breaks_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_maximum_breaks_classification(3, &data).unwrap() == get_maximum_breaks_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_maximum_breaks_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_maximum_breaks_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_maximum_breaks_classification(num_bins, data))
+}
+
+/// Returns a vector of breaks placed at the midpoints of the widest gaps between consecutive unique values given the desired number of bins and a dataset
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
+/// * If several gaps are equally wide, the gaps between lower values are used first
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_maximum_breaks;
+///
+/// let data: Vec<i32> = vec![1, 2, 6, 7, 8, 15, 16];
+/// let num_bins = 3;
+///
+/// let result: Vec<f64> = get_maximum_breaks(num_bins, &data);
+///
+/// assert_eq!(result, vec![4.0, 11.5]);
+/// ```
+pub fn get_maximum_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    let num_unique_vals = unique_val_map.len();
+    let true_num_bins = core::cmp::min(num_unique_vals, num_bins);
+    if true_num_bins <= 1 {
+        return vec![];
+    }
+
+    // Indices of the gaps between consecutive unique values, widest first
+    let mut gaps: Vec<usize> = (1..num_unique_vals).collect();
+    gaps.sort_by(|a, b| {
+        let gap_a = unique_val_map[*a].val - unique_val_map[*a - 1].val;
+        let gap_b = unique_val_map[*b].val - unique_val_map[*b - 1].val;
+        gap_b.partial_cmp(&gap_a).unwrap().then(a.cmp(b))
+    });
+    gaps.truncate(true_num_bins - 1);
+    gaps.sort_unstable();
+
+    let mut breaks: Vec<f64> = vec![];
+    for idx in gaps {
+        breaks.push((unique_val_map[idx - 1].val + unique_val_map[idx].val) / 2.0);
+    }
+
+    breaks
+}
+
+/// Returns the midpoints of the gaps between consecutive unique values of a dataset that are wider than a given multiple of the average gap, as candidate locations for manual breaks
+///
+/// Unlike get_maximum_breaks, which always uses the widest gaps for a fixed number of breaks, the number of candidates depends on how clearly the data separates
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to search for gaps
+/// * `min_gap_ratio` - The multiple of the average gap between consecutive unique values (the data's range divided by the number of gaps) that a gap must exceed
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns no midpoints if the data contains fewer than two unique values
+/// * The midpoints are returned in ascending order
+///
+/// # Examples
+///
+/// ```
+/// use classify::find_natural_gaps;
+///
+/// let data: Vec<i32> = vec![1, 2, 3, 4, 20, 21, 22, 50];
+///
+/// assert_eq!(find_natural_gaps(&data, 3.0), vec![36.0]);
+/// assert_eq!(find_natural_gaps(&data, 2.0), vec![12.0, 36.0]);
+/// ```
+pub fn find_natural_gaps<T: ToPrimitive>(data: &[T], min_gap_ratio: f64) -> Vec<f64> {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    let num_unique_vals = unique_val_map.len();
+    if num_unique_vals < 2 {
+        return vec![];
+    }
+
+    let range = unique_val_map[num_unique_vals - 1].val - unique_val_map[0].val;
+    let min_gap = min_gap_ratio * range / (num_unique_vals - 1) as f64;
+
+    let mut midpoints: Vec<f64> = vec![];
+    for pair in unique_val_map.windows(2) {
+        if pair[1].val - pair[0].val > min_gap {
+            midpoints.push((pair[0].val + pair[1].val) / 2.0);
+        }
+    }
+    midpoints
+}
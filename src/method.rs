@@ -0,0 +1,439 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::ToPrimitive;
+
+use crate::error::ClassifyError;
+use crate::metrics::goodness_of_variance_fit;
+use crate::percentile::PercentileInterpolation;
+use crate::summary::DataSummary;
+use crate::utilities::{to_vec_f64, Classification};
+
+/// Represents a classification method chosen at runtime, holding any parameters the method needs besides the number of bins
+///
+/// Every variant is classified through the same `classify` call, which makes it possible to let users pick a method (e.g. from a dropdown) without matching on a dozen free functions
+///
+/// # Examples
+///
+/// ```
+/// use classify::{ClassificationMethod, get_jenks_classification};
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let method = ClassificationMethod::Jenks;
+///
+/// assert_eq!(method.classify(3, &data).unwrap(), get_jenks_classification(3, &data));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassificationMethod {
+    /// Jenks Natural Breaks
+    Jenks,
+    /// Quantile Breaks
+    Quantile,
+    /// Equal Interval
+    EqualInterval,
+    /// Standard Deviation with the given bin size (in standard deviations); the number of bins is ignored
+    StdDev(f64),
+    /// Head-Tail Breaks; the number of bins is ignored
+    HeadTail,
+    /// Hinge with the given hinge coefficient; the number of bins is ignored
+    Hinge(f64),
+    /// Box Plot; the number of bins is ignored
+    Boxplot,
+    /// Geometric Interval
+    GeometricInterval,
+    /// Arithmetic Interval
+    ArithmeticInterval,
+    /// Harmonic Interval
+    HarmonicInterval,
+    /// Square Root Interval
+    SqrtInterval,
+    /// Fisher-Jenks
+    FisherJenks,
+    /// Maximum Breaks
+    MaximumBreaks,
+    /// Pretty Breaks
+    Pretty,
+    /// k-means with the given maximum number of iterations
+    KMeans(usize),
+    /// Logarithmic Interval
+    Logarithmic,
+    /// Unique Value, with the number of bins as the maximum number of classes
+    UniqueValue,
+    /// Ckmeans
+    Ckmeans,
+    /// Defined Interval with the given bin width; the number of bins is ignored
+    DefinedInterval(f64),
+    /// Percentile with the given percentiles (0 to 100) and linear interpolation; the number of bins is ignored
+    Percentile(Vec<f64>),
+    /// Manually defined breaks; the number of bins is ignored
+    Manual(Vec<f64>),
+}
+
+/// Represents something noteworthy that happened while generating a Classification without preventing it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// NaN or infinite data points were left out of the Classification
+    DroppedNonFinite {
+        /// The number of data points that were left out
+        count: usize,
+    },
+    /// The method produced fewer bins than requested, such as when the data has fewer unique values than the number of bins asked for
+    BinsReduced {
+        /// The number of bins that was asked for
+        requested: usize,
+        /// The number of bins in the Classification
+        actual: usize,
+    },
+}
+
+/// Represents a Classification together with details on how it was generated, as returned by ClassificationMethod::classify_with_result
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassificationResult {
+    /// The generated Classification
+    pub classification: Classification,
+    /// The method that generated the Classification
+    pub method: ClassificationMethod,
+    /// The number of bins that was asked for
+    pub requested_bins: usize,
+    /// The number of bins in the Classification, which can be less than requested_bins (for example, methods such as Jenks produce at most one bin per unique value)
+    pub actual_bins: usize,
+    /// The goodness of variance fit of the Classification on the classified data (see goodness_of_variance_fit)
+    pub gvf: f64,
+    /// Anything noteworthy that happened while generating the Classification
+    pub warnings: Vec<Warning>,
+}
+
+impl ClassificationMethod {
+    /// Returns the methods that can be offered for selection without further user input, with default parameters (1 standard deviation, a hinge coefficient of 1.5, and 100 k-means iterations) where needed
+    ///
+    /// Defined Interval, Percentile, and Manual are left out since they depend on user-supplied values
+    pub fn all() -> Vec<ClassificationMethod> {
+        vec![
+            ClassificationMethod::Jenks,
+            ClassificationMethod::Quantile,
+            ClassificationMethod::EqualInterval,
+            ClassificationMethod::StdDev(1.0),
+            ClassificationMethod::HeadTail,
+            ClassificationMethod::Hinge(1.5),
+            ClassificationMethod::Boxplot,
+            ClassificationMethod::GeometricInterval,
+            ClassificationMethod::ArithmeticInterval,
+            ClassificationMethod::HarmonicInterval,
+            ClassificationMethod::SqrtInterval,
+            ClassificationMethod::FisherJenks,
+            ClassificationMethod::MaximumBreaks,
+            ClassificationMethod::Pretty,
+            ClassificationMethod::KMeans(100),
+            ClassificationMethod::Logarithmic,
+            ClassificationMethod::UniqueValue,
+            ClassificationMethod::Ckmeans,
+        ]
+    }
+
+    /// Returns a human-readable name for the method, suitable for a menu
+    pub fn name(&self) -> &'static str {
+        match self {
+            ClassificationMethod::Jenks => "Jenks",
+            ClassificationMethod::Quantile => "Quantile",
+            ClassificationMethod::EqualInterval => "Equal Interval",
+            ClassificationMethod::StdDev(_) => "Standard Deviation",
+            ClassificationMethod::HeadTail => "Head-Tail",
+            ClassificationMethod::Hinge(_) => "Hinge",
+            ClassificationMethod::Boxplot => "Box Plot",
+            ClassificationMethod::GeometricInterval => "Geometric Interval",
+            ClassificationMethod::ArithmeticInterval => "Arithmetic Interval",
+            ClassificationMethod::HarmonicInterval => "Harmonic Interval",
+            ClassificationMethod::SqrtInterval => "Square Root Interval",
+            ClassificationMethod::FisherJenks => "Fisher-Jenks",
+            ClassificationMethod::MaximumBreaks => "Maximum Breaks",
+            ClassificationMethod::Pretty => "Pretty Breaks",
+            ClassificationMethod::KMeans(_) => "k-means",
+            ClassificationMethod::Logarithmic => "Logarithmic Interval",
+            ClassificationMethod::UniqueValue => "Unique Value",
+            ClassificationMethod::Ckmeans => "Ckmeans",
+            ClassificationMethod::DefinedInterval(_) => "Defined Interval",
+            ClassificationMethod::Percentile(_) => "Percentile",
+            ClassificationMethod::Manual(_) => "Manual",
+        }
+    }
+
+    /// Returns whether this method uses the number of bins passed to classify
+    fn takes_num_bins(&self) -> bool {
+        !matches!(
+            self,
+            ClassificationMethod::StdDev(_)
+                | ClassificationMethod::HeadTail
+                | ClassificationMethod::Hinge(_)
+                | ClassificationMethod::Boxplot
+                | ClassificationMethod::DefinedInterval(_)
+                | ClassificationMethod::Percentile(_)
+                | ClassificationMethod::Manual(_)
+        )
+    }
+
+    /// Returns a Classification object generated through this method given the desired number of bins and one-dimensional data, or an error if one cannot be generated
+    ///
+    /// # Arguments
+    ///
+    /// * `num_bins` - An integer (usize) representing the desired number of bins (ignored by methods that do not take one)
+    /// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+    ///
+    /// # Edge Cases
+    ///
+    /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+    /// * Every method returns ClassifyError::EmptyData for empty data and ClassifyError::NonFinite for data containing NaN or infinite values, along with its own errors (e.g. ClassifyError::ZeroBins)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::ClassificationMethod;
+    /// use classify::{Classification, Bin};
+    ///
+    /// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+    /// let method = ClassificationMethod::Manual(vec![3.0]);
+    /// let expected: Classification = vec![
+    ///     Bin{bin_start: 1.0, bin_end: 3.0, count: 2},
+    ///     Bin{bin_start: 3.0, bin_end: 8.0, count: 4}
+    /// ];
+    ///
+    /// assert_eq!(method.classify(2, &data).unwrap(), expected);
+    /// ```
+    pub fn classify<T: ToPrimitive>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+    ) -> Result<Classification, ClassifyError> {
+        match self {
+            ClassificationMethod::Jenks => {
+                crate::jenks::try_get_jenks_classification(num_bins, data)
+            }
+            ClassificationMethod::Quantile => {
+                crate::quantile::try_get_quantile_classification(num_bins, data)
+            }
+            ClassificationMethod::EqualInterval => {
+                crate::equal_interval::try_get_equal_interval_classification(num_bins, data)
+            }
+            ClassificationMethod::StdDev(bin_size) => {
+                crate::standard_deviation::try_get_st_dev_classification(*bin_size, data)
+            }
+            ClassificationMethod::HeadTail => {
+                crate::head_tail::try_get_head_tail_classification(data)
+            }
+            ClassificationMethod::Hinge(hinge_coefficient) => {
+                crate::hinge::try_get_hinge_classification(*hinge_coefficient, data)
+            }
+            ClassificationMethod::Boxplot => crate::hinge::try_get_boxplot_classification(data),
+            ClassificationMethod::GeometricInterval => {
+                crate::geometric_interval::try_get_geometric_interval_classification(num_bins, data)
+            }
+            ClassificationMethod::ArithmeticInterval => {
+                crate::arithmetic_interval::try_get_arithmetic_interval_classification(
+                    num_bins, data,
+                )
+            }
+            ClassificationMethod::HarmonicInterval => {
+                crate::harmonic_interval::get_harmonic_interval_classification(num_bins, data)
+            }
+            ClassificationMethod::SqrtInterval => {
+                crate::sqrt_interval::get_sqrt_interval_classification(num_bins, data)
+            }
+            ClassificationMethod::FisherJenks => {
+                crate::fisher_jenks::try_get_fisher_jenks_classification(num_bins, data)
+            }
+            ClassificationMethod::MaximumBreaks => {
+                crate::maximum_breaks::try_get_maximum_breaks_classification(num_bins, data)
+            }
+            ClassificationMethod::Pretty => {
+                crate::pretty::try_get_pretty_classification(num_bins, data)
+            }
+            ClassificationMethod::KMeans(max_iter) => {
+                crate::kmeans::try_get_kmeans_classification(num_bins, data, *max_iter)
+            }
+            ClassificationMethod::Logarithmic => {
+                crate::logarithmic::get_logarithmic_classification(num_bins, data)
+            }
+            ClassificationMethod::UniqueValue => {
+                crate::unique_value::try_get_unique_value_classification(data, Some(num_bins))
+            }
+            ClassificationMethod::Ckmeans => {
+                crate::ckmeans::try_get_ckmeans_classification(num_bins, data)
+            }
+            ClassificationMethod::DefinedInterval(interval) => {
+                crate::defined_interval::get_defined_interval_classification(*interval, data)
+            }
+            ClassificationMethod::Percentile(percentiles) => {
+                crate::percentile::get_percentile_classification(
+                    percentiles,
+                    data,
+                    PercentileInterpolation::Linear,
+                )
+            }
+            ClassificationMethod::Manual(breaks) => {
+                crate::manual::get_manual_classification(breaks, data)
+            }
+        }
+    }
+
+    /// Returns a ClassificationResult from this method given the desired number of bins and one-dimensional data, reporting the number of bins produced, the achieved goodness of variance fit, and any warnings, or an error if no Classification can be generated
+    ///
+    /// Unlike classify, NaN and infinite data points are dropped (and reported through Warning::DroppedNonFinite) rather than rejected, and a method that produces fewer bins than requested is reported through Warning::BinsReduced
+    ///
+    /// # Arguments
+    ///
+    /// * `num_bins` - An integer (usize) representing the desired number of bins (ignored by methods that do not take one)
+    /// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+    ///
+    /// # Edge Cases
+    ///
+    /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+    /// * Returns ClassifyError::EmptyData if no finite data points remain
+    /// * If the method cannot produce requested_bins bins from the data, actual_bins reports the number it did produce. Warning::BinsReduced is only added for methods that take a number of bins
+    /// * If every remaining data point shares the same value, the gvf is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::{ClassificationMethod, Warning};
+    ///
+    /// let data: Vec<f64> = vec![1.0, 2.0, f64::NAN, 4.0, 5.0, 7.0, 8.0];
+    /// let result = ClassificationMethod::Jenks.classify_with_result(3, &data).unwrap();
+    ///
+    /// assert_eq!(result.actual_bins, 3);
+    /// assert_eq!(result.gvf, 1.0 - 1.5 / 37.5);
+    /// assert_eq!(result.warnings, vec![Warning::DroppedNonFinite { count: 1 }]);
+    /// ```
+    pub fn classify_with_result<T: ToPrimitive>(
+        &self,
+        num_bins: usize,
+        data: &[T],
+    ) -> Result<ClassificationResult, ClassifyError> {
+        let all_vals = to_vec_f64(data);
+        let vals: Vec<f64> = all_vals
+            .iter()
+            .copied()
+            .filter(|val| val.is_finite())
+            .collect();
+
+        let mut warnings: Vec<Warning> = vec![];
+        if vals.len() < all_vals.len() {
+            warnings.push(Warning::DroppedNonFinite {
+                count: all_vals.len() - vals.len(),
+            });
+        }
+
+        let classification = self.classify(num_bins, &vals)?;
+        if self.takes_num_bins() && classification.len() < num_bins {
+            warnings.push(Warning::BinsReduced {
+                requested: num_bins,
+                actual: classification.len(),
+            });
+        }
+        Ok(ClassificationResult {
+            gvf: goodness_of_variance_fit(&classification, &vals),
+            actual_bins: classification.len(),
+            classification,
+            method: self.clone(),
+            requested_bins: num_bins,
+            warnings,
+        })
+    }
+}
+
+/// Returns a suggested ClassificationMethod for a dataset based on the shape of its distribution, as a starting point for users unsure which method to pick
+///
+/// The skewness and excess kurtosis of the data (see DataSummary) are compared against fixed thresholds:
+///
+/// * Heavily right-skewed data (a skewness above 2) gets ClassificationMethod::Logarithmic if every value is positive, or ClassificationMethod::HeadTail otherwise, so that the long tail does not squeeze most values into one bin
+/// * Near-uniform data (a skewness between -0.5 and 0.5 and an excess kurtosis below -1, uniform data having -1.2) gets ClassificationMethod::EqualInterval
+/// * All other data gets ClassificationMethod::Jenks
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Empty or constant data, or data containing NaN values, gets ClassificationMethod::Jenks since its skewness and kurtosis are NaN
+///
+/// # Examples
+///
+/// ```
+/// use classify::{recommend_method, ClassificationMethod};
+///
+/// let uniform: Vec<f64> = (0..100).map(|i| i as f64).collect();
+/// let skewed: Vec<f64> = (1..=100).map(|i| 1000.0 / (i * i) as f64).collect();
+///
+/// assert_eq!(recommend_method(&uniform), ClassificationMethod::EqualInterval);
+/// assert_eq!(recommend_method(&skewed), ClassificationMethod::Logarithmic);
+/// ```
+pub fn recommend_method<T: ToPrimitive>(data: &[T]) -> ClassificationMethod {
+    let summary = DataSummary::from_data(data);
+
+    if summary.skewness > 2.0 {
+        if summary.min > 0.0 {
+            ClassificationMethod::Logarithmic
+        } else {
+            ClassificationMethod::HeadTail
+        }
+    } else if summary.skewness.abs() < 0.5 && summary.kurtosis < -1.0 {
+        ClassificationMethod::EqualInterval
+    } else {
+        ClassificationMethod::Jenks
+    }
+}
+
+/// Returns one Classification (or error) per dataset, each generated through the same method and number of bins, in the order of the datasets
+///
+/// With the "rayon" feature, the datasets are classified in parallel
+///
+/// # Arguments
+///
+/// * `method` - The ClassificationMethod used for every dataset
+/// * `num_bins` - An integer (usize) representing the desired number of bins (ignored by methods that do not take one)
+/// * `datasets` - A reference to a collection of datasets, each a vector of unsorted data points
+///
+/// # Edge Cases
+///
+/// * A dataset that cannot be classified (see ClassificationMethod::classify) produces an error in its position without affecting the others
+/// * Methods that sample randomly (such as Jenks) use their default seed for every dataset, so the results match classifying each dataset on its own
+///
+/// # Examples
+///
+/// ```
+/// use classify::{classify_batch, get_quantile_classification};
+/// use classify::{ClassificationMethod, ClassifyError};
+///
+/// let datasets: Vec<Vec<f64>> = vec![
+///     vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0],
+///     vec![],
+///     vec![10.0, 20.0, 30.0, 40.0],
+/// ];
+///
+/// let results = classify_batch(&ClassificationMethod::Quantile, 2, &datasets);
+///
+/// assert_eq!(results[0], Ok(get_quantile_classification(2, &datasets[0])));
+/// assert_eq!(results[1], Err(ClassifyError::EmptyData));
+/// assert_eq!(results[2], Ok(get_quantile_classification(2, &datasets[2])));
+/// ```
+pub fn classify_batch(
+    method: &ClassificationMethod,
+    num_bins: usize,
+    datasets: &[Vec<f64>],
+) -> Vec<Result<Classification, ClassifyError>> {
+    #[cfg(not(feature = "rayon"))]
+    {
+        datasets
+            .iter()
+            .map(|data| method.classify(num_bins, data))
+            .collect()
+    }
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        datasets
+            .par_iter()
+            .map(|data| method.classify(num_bins, data))
+            .collect()
+    }
+}
@@ -0,0 +1,492 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::ToPrimitive;
+
+use crate::jenks::calc_ssd;
+use crate::percentile::{calc_percentile, PercentileInterpolation};
+use crate::utilities::{classify_val, to_vec_f64, Classification};
+
+/// Calculates the Tabular Accuracy Index (TAI) of a Classification for a dataset
+///
+/// Following Jenks and Caspall (1971), the TAI is 1 minus the ratio of the sum of absolute deviations of each data point from its class median to the sum of absolute deviations from the overall median, which makes it the same measure as goodness_of_absolute_deviation_fit under the name used in the cartographic literature. It ranges from 0 to 1 (as the median minimizes the sum of absolute deviations, no classification does worse than a single class): values near 1 indicate classes that describe the data closely, while values near 0 indicate a classification no better than a single class
+///
+/// # Arguments
+///
+/// * `classification` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored
+/// * If every data point shares the same value, the result is NaN
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, tabular_accuracy_index};
+/// use classify::Classification;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let classification: Classification = get_jenks_classification(3, &data);
+///
+/// assert_eq!(tabular_accuracy_index(&classification, &data), 1.0 - 3.0 / 13.0);
+/// ```
+pub fn tabular_accuracy_index<T: ToPrimitive>(classification: &Classification, data: &[T]) -> f64 {
+    goodness_of_absolute_deviation_fit(classification, data)
+}
+
+/// Calculates the Goodness of Variance Fit (GVF) of a Classification for a dataset
+///
+/// The GVF is 1 minus the ratio of the sum of squared deviations of each data point from its class mean to the sum of squared deviations from the overall mean. It is the measure that Jenks Natural Breaks maximizes, so it allows comparing the result of any method against Jenks. Values near 1 indicate classes that describe the data closely
+///
+/// # Arguments
+///
+/// * `classification` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored
+/// * If every data point shares the same value, the result is NaN
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, goodness_of_variance_fit};
+/// use classify::Classification;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let classification: Classification = get_jenks_classification(3, &data);
+///
+/// assert_eq!(goodness_of_variance_fit(&classification, &data), 1.0 - 1.5 / 37.5);
+/// ```
+pub fn goodness_of_variance_fit<T: ToPrimitive>(
+    classification: &Classification,
+    data: &[T],
+) -> f64 {
+    let classes = group_by_bin(classification, data);
+    let all_vals: Vec<f64> = classes.iter().flatten().copied().collect();
+
+    let total_ssd = calc_ssd(&all_vals);
+    let class_ssd: f64 = classes.iter().map(|class| calc_ssd(class)).sum();
+
+    1.0 - class_ssd / total_ssd
+}
+
+/// Calculates the information lost by representing each data point by the mean of its class, as the total squared error of that representation relative to the sum of squared deviations from the overall mean
+///
+/// This is 1 minus the goodness of variance fit, and so matches 1 - calc_gvf for the break indices of the Classification. A loss of 0 means the class means reproduce the data exactly, while a loss of 1 means the classes carry no more information than the overall mean
+///
+/// # Arguments
+///
+/// * `c` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored
+/// * If every data point shares the same value, the result is NaN
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, information_loss};
+/// use classify::Classification;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let classification: Classification = get_jenks_classification(3, &data);
+///
+/// assert_eq!(information_loss(&classification, &data), 1.5 / 37.5);
+/// ```
+pub fn information_loss<T: ToPrimitive>(c: &Classification, data: &[T]) -> f64 {
+    let classes = group_by_bin(c, data);
+    let all_vals: Vec<f64> = classes.iter().flatten().copied().collect();
+
+    let mut squared_error = 0.0;
+    for class in &classes {
+        let class_mean = calc_mean(class);
+        for val in class {
+            squared_error += (val - class_mean) * (val - class_mean);
+        }
+    }
+
+    squared_error / calc_ssd(&all_vals)
+}
+
+/// Calculates the within-class sum of squared deviations (SSD) of each bin of a Classification for a dataset, to find the classes that fit the data poorly
+///
+/// These are the per-class terms summed in the numerator of the goodness of variance fit, so a bin with a large SSD relative to the others is the one holding the GVF down
+///
+/// # Arguments
+///
+/// * `c` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored
+/// * The SSD of a bin without data points is 0
+///
+/// # Examples
+///
+/// ```
+/// use classify::per_class_variance;
+/// use classify::{Classification, Bin};
+///
+/// let classification: Classification = vec![
+///     Bin{bin_start: 0.0, bin_end: 10.0, count: 3},
+///     Bin{bin_start: 10.0, bin_end: 20.0, count: 2}
+/// ];
+/// let data: Vec<f64> = vec![1.0, 2.0, 3.0, 10.0, 20.0];
+///
+/// assert_eq!(per_class_variance(&classification, &data), vec![2.0, 50.0]);
+/// ```
+pub fn per_class_variance<T: ToPrimitive>(c: &Classification, data: &[T]) -> Vec<f64> {
+    group_by_bin(c, data)
+        .iter()
+        .map(|class| calc_ssd(class))
+        .collect()
+}
+
+/// Calculates the sum of absolute deviations of each bin's data points from the bin's median for a Classification and a dataset, to find classes that are skewed or fit the data poorly
+///
+/// The median (rather than the mean) minimizes the sum of absolute deviations, and these are the per-class terms summed in the numerator of the goodness of absolute deviation fit
+///
+/// # Arguments
+///
+/// * `c` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored
+/// * The median of a bin with an even number of data points is the average of its two middle values
+/// * The sum of a bin without data points is 0
+///
+/// # Examples
+///
+/// ```
+/// use classify::per_class_absolute_deviation;
+/// use classify::{Classification, Bin};
+///
+/// let classification: Classification = vec![
+///     Bin{bin_start: 0.0, bin_end: 10.0, count: 3},
+///     Bin{bin_start: 10.0, bin_end: 20.0, count: 2}
+/// ];
+/// let data: Vec<f64> = vec![1.0, 2.0, 6.0, 10.0, 20.0];
+///
+/// assert_eq!(per_class_absolute_deviation(&classification, &data), vec![5.0, 10.0]);
+/// ```
+pub fn per_class_absolute_deviation<T: ToPrimitive>(c: &Classification, data: &[T]) -> Vec<f64> {
+    group_by_bin(c, data)
+        .iter()
+        .map(|class| {
+            let class_median = calc_median(class);
+            class.iter().map(|val| (val - class_median).abs()).sum()
+        })
+        .collect()
+}
+
+/// Calculates the Goodness of Absolute Deviation Fit (GADF) of a Classification for a dataset
+///
+/// The GADF is 1 minus the ratio of the sum of absolute deviations of each data point from its class median to the sum of absolute deviations from the overall median. It is the absolute-deviation counterpart to the goodness of variance fit and is less sensitive to outliers. Values near 1 indicate classes that describe the data closely
+///
+/// # Arguments
+///
+/// * `classification` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored
+/// * If every data point shares the same value, the result is NaN
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, goodness_of_absolute_deviation_fit};
+/// use classify::Classification;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let classification: Classification = get_jenks_classification(3, &data);
+///
+/// assert_eq!(goodness_of_absolute_deviation_fit(&classification, &data), 1.0 - 3.0 / 13.0);
+/// ```
+pub fn goodness_of_absolute_deviation_fit<T: ToPrimitive>(
+    classification: &Classification,
+    data: &[T],
+) -> f64 {
+    let classes = group_by_bin(classification, data);
+    let all_vals: Vec<f64> = classes.iter().flatten().copied().collect();
+
+    let global_median = calc_median(&all_vals);
+    let mut total_dev = 0.0;
+    for val in &all_vals {
+        total_dev += (val - global_median).abs();
+    }
+
+    let mut class_dev = 0.0;
+    for class in &classes {
+        let class_median = calc_median(class);
+        for val in class {
+            class_dev += (val - class_median).abs();
+        }
+    }
+
+    1.0 - class_dev / total_dev
+}
+
+/// Calculates a silhouette score of a Classification for a dataset, adapted to one dimension by comparing each data point to class means
+///
+/// For each data point, the cohesion a is its distance to the mean of its own class and the separation b is its distance to the nearest mean of another class, giving it a score of (b - a) / max(a, b). The result is the average score over all data points, from -1 to 1. Unlike the goodness of variance fit, which only rises as classes are added, it drops both when distinct groups share a class and when a tight group is split, so it can compare Classifications with different numbers of bins. Values near 1 indicate compact, well-separated classes
+///
+/// # Arguments
+///
+/// * `c` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored, as are bins without data points
+/// * A data point lying on its class mean and on another class mean scores 0
+/// * If fewer than two bins contain data points, the result is NaN
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, silhouette_score_1d};
+/// use classify::Classification;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let classification: Classification = get_jenks_classification(3, &data);
+/// let score = silhouette_score_1d(&classification, &data);
+///
+/// assert!((score - (2.0 * 6.0 / 7.0 + 4.0 * 0.8) / 6.0).abs() < 1e-12);
+/// ```
+pub fn silhouette_score_1d<T: ToPrimitive>(c: &Classification, data: &[T]) -> f64 {
+    let classes: Vec<Vec<f64>> = group_by_bin(c, data)
+        .into_iter()
+        .filter(|class| !class.is_empty())
+        .collect();
+    if classes.len() < 2 {
+        return f64::NAN;
+    }
+    let means: Vec<f64> = classes.iter().map(|class| calc_mean(class)).collect();
+
+    let mut total_score = 0.0;
+    let mut num_vals = 0;
+    for (i, class) in classes.iter().enumerate() {
+        for val in class {
+            let cohesion = (val - means[i]).abs();
+            let separation = means
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, mean)| (val - mean).abs())
+                .fold(f64::INFINITY, f64::min);
+            let scale = cohesion.max(separation);
+            if scale > 0.0 {
+                total_score += (separation - cohesion) / scale;
+            }
+            num_vals += 1;
+        }
+    }
+
+    total_score / num_vals as f64
+}
+
+/// Calculates the Calinski-Harabasz index (variance ratio criterion) of a Classification for a dataset
+///
+/// The index is the between-class sum of squared deviations divided by the within-class sum, scaled by (n - k) / (k - 1) for n data points in k classes. The between-class sum is the total sum of squared deviations less the within-class sum, the same decomposition behind the goodness of variance fit, but the scaling penalizes extra classes. Classifying a dataset with an increasing number of bins and picking the number with the highest index suggests its natural number of classes (the index of large, evenly spread classes can keep rising as they are split, so compare it over a modest range of bin counts)
+///
+/// # Arguments
+///
+/// * `c` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored, as are bins without data points
+/// * If fewer than two bins contain data points, or every data point lies in its own class, the result is NaN
+/// * If every class holds a single repeated value, the result is infinite
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, calinski_harabasz_1d};
+/// use classify::Classification;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let classification: Classification = get_jenks_classification(3, &data);
+///
+/// assert_eq!(calinski_harabasz_1d(&classification, &data), (36.0 / 2.0) / (1.5 / 3.0));
+/// ```
+pub fn calinski_harabasz_1d<T: ToPrimitive>(c: &Classification, data: &[T]) -> f64 {
+    let classes: Vec<Vec<f64>> = group_by_bin(c, data)
+        .into_iter()
+        .filter(|class| !class.is_empty())
+        .collect();
+    let num_classes = classes.len();
+    let all_vals: Vec<f64> = classes.iter().flatten().copied().collect();
+    let num_vals = all_vals.len();
+    if num_classes < 2 || num_vals <= num_classes {
+        return f64::NAN;
+    }
+
+    let within_ssd: f64 = classes.iter().map(|class| calc_ssd(class)).sum();
+    let between_ssd = calc_ssd(&all_vals) - within_ssd;
+
+    (between_ssd / (num_classes - 1) as f64) / (within_ssd / (num_vals - num_classes) as f64)
+}
+
+/// Calculates the Davies-Bouldin index of a Classification for a dataset
+///
+/// The spread of each class is the mean distance of its data points to the class mean. Each class is compared to every other class by the sum of their spreads divided by the distance between their means, and the index is the average over classes of the largest such ratio. Lower values indicate compact, well-separated classes, so classifying a dataset with an increasing number of bins and picking the number with the lowest index suggests its natural number of classes
+///
+/// # Arguments
+///
+/// * `c` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to evaluate the Classification against
+///
+/// # Edge Cases
+///
+/// * Data points outside of the Classification's range are ignored, as are bins without data points
+/// * A class with a single data point (or a single repeated value) has a spread of 0, and the class means of a Classification always differ, so no ratio divides by zero
+/// * If fewer than two bins contain data points, the result is NaN
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_jenks_classification, davies_bouldin_1d};
+/// use classify::Classification;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let classification: Classification = get_jenks_classification(3, &data);
+/// let index = davies_bouldin_1d(&classification, &data);
+///
+/// assert!((index - 1.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn davies_bouldin_1d<T: ToPrimitive>(c: &Classification, data: &[T]) -> f64 {
+    let classes: Vec<Vec<f64>> = group_by_bin(c, data)
+        .into_iter()
+        .filter(|class| !class.is_empty())
+        .collect();
+    let num_classes = classes.len();
+    if num_classes < 2 {
+        return f64::NAN;
+    }
+
+    let means: Vec<f64> = classes.iter().map(|class| calc_mean(class)).collect();
+    let spreads: Vec<f64> = classes
+        .iter()
+        .zip(means.iter())
+        .map(|(class, mean)| {
+            class.iter().map(|val| (val - mean).abs()).sum::<f64>() / class.len() as f64
+        })
+        .collect();
+
+    let mut total_ratio = 0.0;
+    for i in 0..num_classes {
+        let mut max_ratio: f64 = 0.0;
+        for j in 0..num_classes {
+            if i != j {
+                let ratio = (spreads[i] + spreads[j]) / (means[i] - means[j]).abs();
+                max_ratio = max_ratio.max(ratio);
+            }
+        }
+        total_ratio += max_ratio;
+    }
+
+    total_ratio / num_classes as f64
+}
+
+/// Returns the fraction of data points that fall into the same-index bin under two Classifications of the same dataset
+///
+/// This is useful for measuring how much class assignments change when a dataset is re-classified (e.g. after an update or with a different method)
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first Classification
+/// * `b` - A reference to the second Classification
+/// * `data` - A reference to a collection of unsorted data points to compare the Classifications on
+///
+/// # Edge Cases
+///
+/// * If the Classifications have different numbers of bins, only data points assigned to bin indices present in both Classifications are compared
+/// * Data points outside of either Classification's range are ignored
+/// * If no data points can be compared, the result is NaN
+///
+/// # Examples
+///
+/// ```
+/// use classify::classification_agreement;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+/// let a: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 3.0, count: 2},
+///     Bin{bin_start: 3.0, bin_end: 4.0, count: 2}
+/// ];
+/// let b: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 2.0, count: 1},
+///     Bin{bin_start: 2.0, bin_end: 4.0, count: 3}
+/// ];
+///
+/// assert_eq!(classification_agreement(&a, &b, &data), 0.75);
+/// ```
+pub fn classification_agreement<T: ToPrimitive>(
+    a: &Classification,
+    b: &Classification,
+    data: &[T],
+) -> f64 {
+    let data = to_vec_f64(data);
+    let shared_bins = core::cmp::min(a.len(), b.len());
+
+    let mut compared = 0;
+    let mut agreed = 0;
+    for val in data {
+        if let (Some(idx_a), Some(idx_b)) = (classify_val(val, a), classify_val(val, b)) {
+            if idx_a < shared_bins && idx_b < shared_bins {
+                compared += 1;
+                if idx_a == idx_b {
+                    agreed += 1;
+                }
+            }
+        }
+    }
+
+    agreed as f64 / compared as f64
+}
+
+/// Sorts data points into the bins of a Classification (dropping any that fall outside of its range), returning one vector of values per bin
+pub fn group_by_bin<T: ToPrimitive>(classification: &Classification, data: &[T]) -> Vec<Vec<f64>> {
+    let data = to_vec_f64(data);
+
+    let mut classes: Vec<Vec<f64>> = vec![vec![]; classification.len()];
+    for val in data {
+        if let Some(idx) = classify_val(val, classification) {
+            classes[idx].push(val);
+        }
+    }
+
+    classes
+}
+
+/// Calculates the arithmetic mean of a collection of values
+pub fn calc_mean(data: &[f64]) -> f64 {
+    let mut mean = 0.0;
+    for val in data {
+        mean += val;
+    }
+    mean / data.len() as f64
+}
+
+/// Calculates the median of a collection of values, averaging the two middle values for collections of even length
+pub fn calc_median(data: &[f64]) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    calc_percentile(50.0, &sorted_data, PercentileInterpolation::Linear)
+}
@@ -0,0 +1,133 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::jenks::calc_mean_and_ssd;
+use crate::utilities::validate_data;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64};
+use num_traits::ToPrimitive;
+
+/// Returns a Classification object following the Nested Means algorithm given the desired number of levels and one-dimensional data
+///
+/// # Arguments
+///
+/// * `levels` - The number of times the data is split at its mean, producing up to 2^levels bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * A group whose values are all equal cannot be split further, so duplicates (or more levels than the data supports) produce fewer than 2^levels bins
+/// * A `levels` of 0 produces a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_nested_means_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 3.0, 6.0, 10.0, 12.0, 14.0, 16.0];
+///
+/// let result: Classification = get_nested_means_classification(2, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 3.0, count: 2},
+///     Bin{bin_start: 3.0, bin_end: 8.0, count: 2},
+///     Bin{bin_start: 8.0, bin_end: 13.0, count: 2},
+///     Bin{bin_start: 13.0, bin_end: 16.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_nested_means_classification<T: ToPrimitive>(
+    levels: usize,
+    data: &[T],
+) -> Classification {
+    let breaks: Vec<f64> = get_nested_means_breaks(levels, data);
+    breaks_to_classification(&breaks, data)
+}
+
+/// Returns a Classification object following the Nested Means algorithm, returning an error instead of panicking if data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `levels` - The number of times the data is split at its mean, producing up to 2^levels bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_nested_means_classification, try_get_nested_means_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_nested_means_classification(2, &data).unwrap() == get_nested_means_classification(2, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_nested_means_classification(2, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_nested_means_classification<T: ToPrimitive>(
+    levels: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_data(data)?;
+    Ok(get_nested_means_classification(levels, data))
+}
+
+/// Returns a vector of breaks generated through the Nested Means algorithm given the desired number of levels and a dataset
+///
+/// The first break is the mean of the data, which splits it into a lower group (values below the mean) and an upper group (values at or above it). Each group is then split at its own mean, recursing `levels` deep
+///
+/// # Arguments
+///
+/// * `levels` - The number of times the data is split at its mean, producing up to 2^levels - 1 breaks
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * A group whose values are all equal cannot be split further, so duplicates (or more levels than the data supports) produce fewer than 2^levels - 1 breaks
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_nested_means_breaks;
+///
+/// let data: Vec<u32> = vec![1, 2, 3, 6, 10, 12, 14, 16];
+///
+/// assert_eq!(get_nested_means_breaks(1, &data), vec![8.0]);
+/// assert_eq!(get_nested_means_breaks(2, &data), vec![3.0, 8.0, 13.0]);
+/// ```
+pub fn get_nested_means_breaks<T: ToPrimitive>(levels: usize, data: &[T]) -> Vec<f64> {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut breaks: Vec<f64> = vec![];
+    nested_means_recursion(&sorted_data, levels, &mut breaks);
+    breaks
+}
+
+/// Recursive function used by get_nested_means_breaks that appends the breaks within a group of data points (sorted, ascending) in ascending order
+fn nested_means_recursion(sorted_data: &[f64], levels: usize, breaks: &mut Vec<f64>) {
+    if levels == 0 || sorted_data.is_empty() {
+        return;
+    }
+
+    let (mean, _) = calc_mean_and_ssd(sorted_data);
+    let split_idx = sorted_data.partition_point(|val| *val < mean);
+    // A group of equal values has its mean at its minimum and cannot be split
+    if split_idx == 0 || split_idx == sorted_data.len() {
+        return;
+    }
+
+    nested_means_recursion(&sorted_data[..split_idx], levels - 1, breaks);
+    breaks.push(mean);
+    nested_means_recursion(&sorted_data[split_idx..], levels - 1, breaks);
+}
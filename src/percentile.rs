@@ -0,0 +1,339 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::manual::validate_breaks;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+use num_traits::ToPrimitive;
+
+/// Represents the rule used to pick a value for a percentile that falls between two data points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentileInterpolation {
+    /// Interpolates linearly between the two closest ranks (the rule used by get_hinge_breaks)
+    Linear,
+    /// Uses the smallest value whose rank is at least the percentile's share of the number of data points
+    NearestRank,
+}
+
+/// Returns a Classification object with breaks at a user-supplied set of percentiles given one-dimensional data
+///
+/// # Arguments
+///
+/// * `percentiles` - A reference to a collection of percentiles (0 to 100, strictly increasing) at which to place breaks; 0 and 100 correspond to the data's minimum and maximum and do not produce breaks
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `interpolation` - The rule used to calculate percentiles that fall between data points
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns `ClassifyError::UnsortedBreaks` if the percentiles are not strictly increasing and `ClassifyError::PercentileOutOfRange` if any lies outside 0 to 100
+/// * Returns `ClassifyError::EmptyData` if data contains no values and `ClassifyError::NonFinite` if it contains NaN or infinite values
+/// * If your dataset contains many duplicates, several percentiles may share a value, in which case the duplicate breaks are removed and fewer bins are produced
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_percentile_classification, PercentileInterpolation};
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<u32> = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// let percentiles = vec![0.0, 10.0, 50.0, 90.0, 100.0];
+///
+/// let result: Classification = get_percentile_classification(&percentiles, &data, PercentileInterpolation::Linear).unwrap();
+/// let expected: Classification = vec![
+///     Bin{bin_start: 0.0, bin_end: 1.0, count: 1},
+///     Bin{bin_start: 1.0, bin_end: 5.0, count: 4},
+///     Bin{bin_start: 5.0, bin_end: 9.0, count: 4},
+///     Bin{bin_start: 9.0, bin_end: 10.0, count: 2}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_percentile_classification<T: ToPrimitive>(
+    percentiles: &[f64],
+    data: &[T],
+    interpolation: PercentileInterpolation,
+) -> Result<Classification, ClassifyError> {
+    let breaks: Vec<f64> = get_percentile_breaks(percentiles, data, interpolation)?;
+    Ok(breaks_to_classification(&breaks, data))
+}
+
+/// Returns a vector of breaks at a user-supplied set of percentiles given a dataset
+///
+/// # Arguments
+///
+/// * `percentiles` - A reference to a collection of percentiles (0 to 100, strictly increasing) at which to place breaks; 0 and 100 correspond to the data's minimum and maximum and do not produce breaks
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+/// * `interpolation` - The rule used to calculate percentiles that fall between data points
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns `ClassifyError::UnsortedBreaks` if the percentiles are not strictly increasing and `ClassifyError::PercentileOutOfRange` if any lies outside 0 to 100
+/// * Returns `ClassifyError::EmptyData` if data contains no values and `ClassifyError::NonFinite` if it contains NaN or infinite values
+/// * If your dataset contains many duplicates, several percentiles may share a value, in which case the duplicate breaks are removed and fewer bins are produced
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_percentile_breaks, PercentileInterpolation};
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+///
+/// let linear = get_percentile_breaks(&[50.0], &data, PercentileInterpolation::Linear).unwrap();
+/// let nearest = get_percentile_breaks(&[50.0], &data, PercentileInterpolation::NearestRank).unwrap();
+///
+/// assert_eq!(linear, vec![2.5]);
+/// assert_eq!(nearest, vec![2.0]);
+/// ```
+pub fn get_percentile_breaks<T: ToPrimitive>(
+    percentiles: &[f64],
+    data: &[T],
+    interpolation: PercentileInterpolation,
+) -> Result<Vec<f64>, ClassifyError> {
+    validate_breaks(percentiles)?;
+    if percentiles.iter().any(|perc| *perc < 0.0 || *perc > 100.0) {
+        return Err(ClassifyError::PercentileOutOfRange);
+    }
+    validate_data(data)?;
+
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let min_val = sorted_data[0];
+    let max_val = sorted_data[sorted_data.len() - 1];
+
+    let mut breaks: Vec<f64> = vec![];
+    for perc in percentiles {
+        let new_break = calc_percentile(*perc, &sorted_data, interpolation);
+        if min_val < new_break && new_break < max_val {
+            breaks.push(new_break);
+        }
+    }
+    breaks.dedup();
+
+    Ok(breaks)
+}
+
+/// Returns a Classification object with a bin for each tail of the data (the lowest and highest `tail_fraction` of the data points) and the data points between them split into `middle_bins` bins of equal counts, along with the (lower, upper) tail thresholds
+///
+/// This yields classes such as the bottom 5%, the middle 90% in equal-count bins, and the top 5%, with percentiles calculated through linear interpolation
+///
+/// # Arguments
+///
+/// * `middle_bins` - An integer (usize) representing the desired number of bins between the tails
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `tail_fraction` - The fraction (0 to 0.5, exclusive of 0.5) of the data points in each tail
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * A tail_fraction of 0 produces no tail bins, so the result matches a quantile classification into middle_bins bins (with linear interpolation) and the thresholds are the data's minimum and maximum
+/// * If your dataset contains many duplicates or few data points, several breaks may share a value, in which case the duplicate breaks are removed and fewer bins are produced
+/// * Panics in the same cases that try_get_tailed_quantile_classification returns an error
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_tailed_quantile_classification;
+///
+/// let data: Vec<f64> = (0..100).map(|i| i as f64).collect();
+///
+/// let (result, (lower, upper)) = get_tailed_quantile_classification(3, &data, 0.05);
+///
+/// assert_eq!((lower, upper), (4.95, 94.05));
+/// assert_eq!(result.iter().map(|bin| bin.count).collect::<Vec<u64>>(), vec![5, 30, 30, 30, 5]);
+/// ```
+pub fn get_tailed_quantile_classification<T: ToPrimitive>(
+    middle_bins: usize,
+    data: &[T],
+    tail_fraction: f64,
+) -> (Classification, (f64, f64)) {
+    match try_get_tailed_quantile_classification(middle_bins, data, tail_fraction) {
+        Ok(result) => result,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Returns a Classification object with a bin for each tail of the data and equal-count bins between them along with the tail thresholds, or an error if the input cannot be classified
+///
+/// # Arguments
+///
+/// * `middle_bins` - An integer (usize) representing the desired number of bins between the tails
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `tail_fraction` - The fraction (0 to 0.5, exclusive of 0.5) of the data points in each tail
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if middle_bins is 0
+/// * Returns ClassifyError::PercentileOutOfRange if tail_fraction is negative, not a number, or at least 0.5
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::try_get_tailed_quantile_classification;
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+/// assert_eq!(try_get_tailed_quantile_classification(2, &data, 0.5), Err(ClassifyError::PercentileOutOfRange));
+/// ```
+pub fn try_get_tailed_quantile_classification<T: ToPrimitive>(
+    middle_bins: usize,
+    data: &[T],
+    tail_fraction: f64,
+) -> Result<(Classification, (f64, f64)), ClassifyError> {
+    validate_num_bins(middle_bins)?;
+    if !(0.0..0.5).contains(&tail_fraction) {
+        return Err(ClassifyError::PercentileOutOfRange);
+    }
+    validate_data(data)?;
+
+    let middle_fraction = 1.0 - 2.0 * tail_fraction;
+    let percentiles: Vec<f64> = (0..=middle_bins)
+        .map(|i| 100.0 * (tail_fraction + middle_fraction * i as f64 / middle_bins as f64))
+        .collect();
+    let breaks = get_percentile_breaks(&percentiles, data, PercentileInterpolation::Linear)?;
+
+    let mut sorted_data = to_vec_f64(data);
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let thresholds = (
+        calc_percentile(
+            percentiles[0],
+            &sorted_data,
+            PercentileInterpolation::Linear,
+        ),
+        calc_percentile(
+            percentiles[middle_bins],
+            &sorted_data,
+            PercentileInterpolation::Linear,
+        ),
+    );
+
+    Ok((breaks_to_classification(&breaks, data), thresholds))
+}
+
+/// Calculates a percentile (0 to 100) of a dataset (sorted, ascending) using the given interpolation rule
+///
+/// # Arguments
+///
+/// * `perc` - The percentile to calculate
+/// * `sorted_data` - A reference to a collection of data points (sorted, ascending)
+/// * `interpolation` - The rule used when the percentile falls between data points
+pub fn calc_percentile(
+    perc: f64,
+    sorted_data: &[f64],
+    interpolation: PercentileInterpolation,
+) -> f64 {
+    let num_vals = sorted_data.len();
+
+    match interpolation {
+        PercentileInterpolation::Linear => {
+            let rank = (perc / 100.0) * (num_vals as f64 - 1.0);
+            let rank_int = rank as usize;
+            if rank_int >= num_vals - 1 {
+                sorted_data[num_vals - 1]
+            } else {
+                let rank_dec = rank - rank_int as f64;
+                sorted_data[rank_int]
+                    + rank_dec * (sorted_data[rank_int + 1] - sorted_data[rank_int])
+            }
+        }
+        PercentileInterpolation::NearestRank => {
+            let rank = ((perc / 100.0) * num_vals as f64).ceil() as usize;
+            sorted_data[rank.clamp(1, num_vals) - 1]
+        }
+    }
+}
+
+/// Returns the share of a dataset's values that are less than or equal to a given value (its empirical cumulative distribution function)
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points
+/// * `value` - The value at which to evaluate the empirical cumulative distribution function
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * NaN and infinite values in data are ignored, and NaN is returned if data contains no other values
+/// * The result is a fraction between 0 and 1; multiply it by 100 to obtain the percentile rank of `value`
+///
+/// # Examples
+///
+/// ```
+/// use classify::empirical_cdf;
+///
+/// let data: Vec<u32> = vec![5, 1, 4, 2, 3];
+///
+/// assert_eq!(empirical_cdf(&data, 0.0), 0.0);
+/// assert_eq!(empirical_cdf(&data, 2.0), 0.4);
+/// assert_eq!(empirical_cdf(&data, 3.5), 0.6);
+/// assert_eq!(empirical_cdf(&data, 10.0), 1.0);
+/// ```
+pub fn empirical_cdf<T: ToPrimitive>(data: &[T], value: f64) -> f64 {
+    let mut sorted_data: Vec<f64> = finite_vals(data);
+    if sorted_data.is_empty() {
+        return f64::NAN;
+    }
+
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let num_at_or_below = sorted_data.partition_point(|val| *val <= value);
+    num_at_or_below as f64 / sorted_data.len() as f64
+}
+
+/// Returns the value at a given percentile (0 to 100) of a dataset
+///
+/// The percentile is calculated with `PercentileInterpolation::Linear`: the data is sorted, the percentile is mapped to the (fractional) rank `p / 100 * (n - 1)`, and the result is interpolated linearly between the values at the two closest ranks, so that 0 yields the minimum, 100 the maximum, and 50 the median
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points
+/// * `p` - The percentile (0 to 100) to calculate
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * NaN and infinite values in data are ignored, and NaN is returned if data contains no other values or `p` is NaN
+/// * Percentiles below 0 or above 100 are clamped to that range
+///
+/// # Examples
+///
+/// ```
+/// use classify::percentile_of;
+///
+/// let data: Vec<f64> = vec![4.0, 1.0, 3.0, 2.0];
+///
+/// assert_eq!(percentile_of(&data, 0.0), 1.0);
+/// assert_eq!(percentile_of(&data, 50.0), 2.5);
+/// assert_eq!(percentile_of(&data, 100.0), 4.0);
+/// ```
+pub fn percentile_of<T: ToPrimitive>(data: &[T], p: f64) -> f64 {
+    let mut sorted_data: Vec<f64> = finite_vals(data);
+    if sorted_data.is_empty() || p.is_nan() {
+        return f64::NAN;
+    }
+
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    calc_percentile(
+        p.clamp(0.0, 100.0),
+        &sorted_data,
+        PercentileInterpolation::Linear,
+    )
+}
+
+/// Converts a dataset to f64, dropping NaN (which cannot be sorted) and infinite values
+fn finite_vals<T: ToPrimitive>(data: &[T]) -> Vec<f64> {
+    let mut vals = to_vec_f64(data);
+    vals.retain(|val| val.is_finite());
+    vals
+}
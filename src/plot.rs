@@ -0,0 +1,86 @@
+use plotters::prelude::*;
+
+use crate::classification::ClassificationExt;
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+
+/// Number of equal-width histogram bars drawn across the range of the data
+const HISTOGRAM_BARS: usize = 100;
+
+/// Renders a histogram of a dataset as a PNG image, coloring the bars by the bin of a Classification they fall into and drawing a vertical line at each break
+///
+/// Intended for eyeballing how well a Classification fits a dataset (e.g. comparing Jenks against quantile breaks)
+///
+/// # Arguments
+///
+/// * `c` - A reference to a Classification generated through any method
+/// * `data` - A reference to a collection of unsorted data points to draw the histogram of
+/// * `path` - The path of the PNG file to write
+///
+/// # Edge Cases
+///
+/// * NaN and infinite data points are left out of the histogram
+/// * The x-axis spans both the data and the Classification, so bins extending beyond the data remain fully visible
+/// * Returns ClassifyError::EmptyData (boxed) if the Classification or the finite data is empty, and any error raised while drawing or writing the image
+///
+/// # Examples
+///
+/// ```no_run
+/// use classify::{get_jenks_classification, render_histogram};
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let classification = get_jenks_classification(3, &data);
+///
+/// render_histogram(&classification, &data, "jenks.png").unwrap();
+/// ```
+pub fn render_histogram(
+    c: &Classification,
+    data: &[f64],
+    path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let vals: Vec<f64> = data.iter().copied().filter(|val| val.is_finite()).collect();
+    if c.is_empty() || vals.is_empty() {
+        return Err(Box::new(ClassifyError::EmptyData));
+    }
+
+    let mut min = c[0].bin_start.min(c[c.len() - 1].bin_end);
+    let mut max = c[0].bin_start.max(c[c.len() - 1].bin_end);
+    for val in &vals {
+        min = min.min(*val);
+        max = max.max(*val);
+    }
+    if max <= min || !(max - min).is_finite() {
+        max = min + 1.0;
+    }
+
+    let width = (max - min) / HISTOGRAM_BARS as f64;
+    let mut counts: Vec<u64> = vec![0; HISTOGRAM_BARS];
+    for val in &vals {
+        let idx = ((val - min) / width) as usize;
+        counts[core::cmp::min(idx, HISTOGRAM_BARS - 1)] += 1;
+    }
+    let top = *counts.iter().max().unwrap() as f64 * 1.05;
+
+    let root = BitMapBackend::new(path, (800, 480)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        .margin(10)
+        .build_cartesian_2d(min..max, 0.0..top)?;
+
+    chart.draw_series(counts.iter().enumerate().map(|(i, count)| {
+        let start = min + i as f64 * width;
+        let bin = c.classify_clamped(start + width / 2.0).unwrap_or(0);
+        Rectangle::new(
+            [(start, 0.0), (start + width, *count as f64)],
+            Palette99::pick(bin).filled(),
+        )
+    }))?;
+    chart.draw_series(
+        c.breaks()
+            .into_iter()
+            .map(|brk| PathElement::new(vec![(brk, 0.0), (brk, top)], BLACK.stroke_width(2))),
+    )?;
+
+    root.present()?;
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+use num_traits::ToPrimitive;
+
+/// Returns a Classification object following the Pretty Breaks algorithm given the desired number of bins and one-dimensional data
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The number of bins produced is chosen so that the breaks are round numbers, so it may differ from num_bins
+/// * The first and last bins are extended to the nearest round numbers at or beyond the data's minimum and maximum
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_pretty_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f32> = vec![3.0, 15.0, 22.0, 41.0, 58.0, 77.0, 97.0];
+/// let num_bins = 5;
+///
+/// let result: Classification = get_pretty_classification(num_bins, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 0.0, bin_end: 20.0, count: 2},
+///     Bin{bin_start: 20.0, bin_end: 40.0, count: 1},
+///     Bin{bin_start: 40.0, bin_end: 60.0, count: 2},
+///     Bin{bin_start: 60.0, bin_end: 80.0, count: 1},
+///     Bin{bin_start: 80.0, bin_end: 100.0, count: 1}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_pretty_classification<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Classification {
+    let breaks: Vec<f64> = get_pretty_breaks(num_bins, data);
+    let mut results = breaks_to_classification(&breaks, data);
+
+    if let Some((step, exp)) = pretty_step(num_bins, data) {
+        let first_bin = 0;
+        let last_bin = results.len() - 1;
+        let start_multiple = (results[first_bin].bin_start / step).floor();
+        let end_multiple = (results[last_bin].bin_end / step).ceil();
+        results[first_bin].bin_start = pretty_multiple(start_multiple, step, exp);
+        results[last_bin].bin_end = pretty_multiple(end_multiple, step, exp);
+    }
+
+    results
+}
+
+/// Returns a Classification object following the Pretty Breaks algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_pretty_classification, try_get_pretty_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_pretty_classification(3, &data).unwrap() == get_pretty_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_pretty_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_pretty_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_pretty_classification(num_bins, data))
+}
+
+/// Returns a vector of round-number breaks (multiples of 1, 2, or 5 times a power of 10) given the desired number of bins and a dataset
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * The number of breaks produced is chosen so that the breaks are round numbers, so it may differ from num_bins - 1
+/// * Only breaks lying strictly between the data's minimum and maximum are returned
+/// * If all data points share the same value, no breaks are generated
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_pretty_breaks;
+///
+/// let data: Vec<u8> = vec![3, 15, 22, 41, 58, 77, 97];
+/// let num_bins = 5;
+///
+/// let result: Vec<f64> = get_pretty_breaks(num_bins, &data);
+///
+/// assert_eq!(result, vec![20.0, 40.0, 60.0, 80.0]);
+/// ```
+pub fn get_pretty_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f64> {
+    let mut breaks: Vec<f64> = vec![];
+
+    let (step, exp) = match pretty_step(num_bins, data) {
+        Some(step) => step,
+        None => return breaks,
+    };
+
+    let data = to_vec_f64(data);
+    let mut min_value = data[0];
+    let mut max_value = data[0];
+    for item in data {
+        if item < min_value {
+            min_value = item;
+        } else if item > max_value {
+            max_value = item;
+        }
+    }
+
+    let mut multiple = (min_value / step).floor() + 1.0;
+    let mut new_break = pretty_multiple(multiple, step, exp);
+    while new_break < max_value {
+        if new_break > min_value {
+            breaks.push(new_break);
+        }
+        multiple += 1.0;
+        let next_break = pretty_multiple(multiple, step, exp);
+        // Steps too small to change values this large (or that overflow) would otherwise never reach the maximum
+        if next_break.is_nan() || next_break <= new_break {
+            break;
+        }
+        new_break = next_break;
+    }
+
+    breaks
+}
+
+/// Chooses a round step size (1, 2, or 5 times a power of 10) close to the data's range divided by the desired number of bins, returning the step along with its power of 10 (or None for constant data)
+fn pretty_step<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Option<(f64, i32)> {
+    let data = to_vec_f64(data);
+
+    let mut min_value = data[0];
+    let mut max_value = data[0];
+    for item in data {
+        if item < min_value {
+            min_value = item;
+        } else if item > max_value {
+            max_value = item;
+        }
+    }
+    if min_value == max_value {
+        return None;
+    }
+
+    let cell = (max_value - min_value) / core::cmp::max(num_bins, 1) as f64;
+    let exp = cell.log10().floor() as i32;
+    let fraction = cell / 10_f64.powi(exp);
+    let nice_fraction = if fraction < 1.5 {
+        1.0
+    } else if fraction < 3.0 {
+        2.0
+    } else if fraction < 7.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    Some((nice_fraction * 10_f64.powi(exp), exp))
+}
+
+/// Multiplies a step by an integer multiple while avoiding floating point noise (e.g. 3 * 0.1 giving 0.30000000000000004) for steps below 1
+fn pretty_multiple(multiple: f64, step: f64, exp: i32) -> f64 {
+    if exp >= 0 {
+        multiple * step
+    } else {
+        let scale = 10_f64.powi(-exp);
+        (multiple * (step * scale).round()) / scale
+    }
+}
@@ -1,5 +1,13 @@
-use crate::utilities::Classification;
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::tdigest::{TDigest, DEFAULT_COMPRESSION};
 use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+use crate::utilities::{Bin, Classification};
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
 use num_traits::ToPrimitive;
 
 /// Returns a Classification object following the Quantile Breaks algorithm given the desired number of bins and one-dimensional data
@@ -14,6 +22,7 @@ use num_traits::ToPrimitive;
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
 /// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
 /// * If your dataset contains many duplicates, there is a chance that the number of bins produced by the algorithm differs from num_bins because duplicate breaks are removed
+/// * When identical values straddle a quantile boundary, the break is placed on that value and all of its occurrences fall into the upper bin (the boundary is pushed left), so bin counts may be uneven
 ///
 /// # Examples
 ///
@@ -38,6 +47,41 @@ pub fn get_quantile_classification<T: ToPrimitive>(num_bins: usize, data: &[T])
     breaks_to_classification(&breaks, data)
 }
 
+/// Returns a Classification object following the Quantile Breaks algorithm, returning an error instead of panicking if num_bins is 0 or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_quantile_classification, try_get_quantile_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_quantile_classification(3, &data).unwrap() == get_quantile_classification(3, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_quantile_classification(3, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_quantile_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    Ok(get_quantile_classification(num_bins, data))
+}
+
 /// Returns a vector of breaks generated through the Quantile Breaks algorithm given the desired number of bins and a dataset
 ///
 /// # Arguments
@@ -50,6 +94,7 @@ pub fn get_quantile_classification<T: ToPrimitive>(num_bins: usize, data: &[T])
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
 /// * The maximum number of bins generated by this algorithm is the number of unique values in the dataset
 /// * If your dataset contains many duplicates, there is a chance that the number of bins produced by the algorithm differs from num_bins because duplicate breaks are removed
+/// * When identical values straddle a quantile boundary, the break is placed on that value and all of its occurrences fall into the upper bin (the boundary is pushed left), so bin counts may be uneven
 ///
 /// # Examples
 ///
@@ -78,9 +123,8 @@ pub fn get_quantile_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f
         sorted_data.push(*item);
     }
     sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    println!("{:#?}", sorted_data);
 
-    let true_num_bins = std::cmp::min(num_vals, num_bins);
+    let true_num_bins = core::cmp::min(num_vals, num_bins);
 
     let mut breaks: Vec<f64> = vec![];
 
@@ -89,10 +133,185 @@ pub fn get_quantile_breaks<T: ToPrimitive>(num_bins: usize, data: &[T]) -> Vec<f
             [((i * num_vals) as f64 / (true_num_bins) as f64) as usize - 1]
             + sorted_data[((i * num_vals) as f64 / (true_num_bins) as f64) as usize])
             / 2.0;
-        breaks.push(new_break);
+        // A break on the minimum would leave the first bin empty since ties are pushed into the upper bin
+        if new_break > sorted_data[0] {
+            breaks.push(new_break);
+        }
     }
 
     breaks.dedup();
 
     breaks
 }
+
+/// Returns a Classification object approximating the Quantile Breaks algorithm given the desired number of bins and a stream of data points, which is read once without being collected into memory
+///
+/// The data points are summarized in a t-digest (a sketch of their distribution in bounded memory), whose estimated quantiles are used as breaks. Unlike get_quantile_classification, breaks are placed at the estimated quantiles themselves rather than between neighboring data points, and the returned counts are the t-digest's estimates of the number of data points in each bin
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - Any iterator or collection of unsorted data points to generate a Classification for
+///
+/// # Edge Cases
+///
+/// * Breaks and counts are estimates, which are closest to the exact quantiles in the tails of the distribution and least accurate near its median
+/// * If the stream contains many duplicates, the number of bins produced may be smaller than num_bins because duplicate breaks are removed
+/// * A num_bins of 0 or 1 produces a single bin spanning the data's range
+/// * Panics if the stream contains no values or values that are not finite
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_quantile_classification_streaming;
+///
+/// let stream = (0..100_000).map(|i| (i % 1000) as f64);
+///
+/// let result = get_quantile_classification_streaming(4, stream);
+///
+/// assert_eq!(result.len(), 4);
+/// assert!((result[1].bin_start - 250.0).abs() < 5.0);
+/// assert_eq!(result.iter().map(|bin| bin.count).sum::<u64>(), 100_000);
+/// ```
+pub fn get_quantile_classification_streaming<I: IntoIterator<Item = f64>>(
+    num_bins: usize,
+    data: I,
+) -> Classification {
+    let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+    for val in data {
+        if let Err(err) = digest.push(val) {
+            panic!("{}", err);
+        }
+    }
+    if digest.count() == 0 {
+        panic!("{}", ClassifyError::EmptyData);
+    }
+    digest_to_quantile_classification(num_bins, &mut digest)
+}
+
+/// Returns a Classification object approximating the Quantile Breaks algorithm given the desired number of bins, one-dimensional data, and the compression of the t-digest used to summarize it, reading the data once in bounded memory instead of sorting a copy
+///
+/// The compression trades memory for accuracy: the t-digest keeps roughly 1.6 times as many centroids as the compression (plus a buffer of 5 times the compression), and the error of the estimated breaks shrinks quickly as the compression grows. A compression of 100 (as used by get_quantile_classification_streaming) typically places breaks within 0.1% of the data's range from the exact quantiles, a compression of 20 within a few percent, and compressions in the hundreds closer still at little extra cost
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `compression` - The compression of the t-digest (values below 10 are raised to 10)
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Breaks are placed at the estimated quantiles themselves rather than between neighboring data points, and the returned counts are the t-digest's estimates of the number of data points in each bin
+/// * If your dataset contains many duplicates, the number of bins produced may be smaller than num_bins because duplicate breaks are removed
+/// * A num_bins of 0 or 1 produces a single bin spanning the data's range
+/// * Panics if data contains no values or values that are not finite (use try_get_approximate_quantile_classification to get an error instead)
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_approximate_quantile_classification, get_quantile_classification};
+/// use classify::ClassificationExt;
+///
+/// let data: Vec<f64> = (0..10_000).map(|i| ((i * 37 % 10_007) as f64).sqrt()).collect();
+///
+/// let exact = get_quantile_classification(4, &data);
+/// let approx = get_approximate_quantile_classification(4, &data, 200.0);
+///
+/// for (approx_break, exact_break) in approx.breaks().iter().zip(exact.breaks()) {
+///     assert!((approx_break - exact_break).abs() < 0.5);
+/// }
+/// ```
+pub fn get_approximate_quantile_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    compression: f64,
+) -> Classification {
+    match try_approximate_quantile_classification(num_bins, data, compression) {
+        Ok(classification) => classification,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Returns a Classification object approximating the Quantile Breaks algorithm through a t-digest with the given compression, or an error if the input cannot be classified
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `compression` - The compression of the t-digest (values below 10 are raised to 10)
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::try_get_approximate_quantile_classification;
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, f64::NAN, 3.0];
+/// assert_eq!(try_get_approximate_quantile_classification(2, &data, 100.0), Err(ClassifyError::NonFinite));
+/// ```
+pub fn try_get_approximate_quantile_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    compression: f64,
+) -> Result<Classification, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    try_approximate_quantile_classification(num_bins, data, compression)
+}
+
+/// Summarizes data in a TDigest with the given compression and builds a quantile Classification from it, or returns an error for empty or non-finite data
+fn try_approximate_quantile_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+    compression: f64,
+) -> Result<Classification, ClassifyError> {
+    let mut digest = TDigest::new(compression);
+    for item in data {
+        digest.push(item.to_f64().unwrap_or(f64::NAN))?;
+    }
+    if digest.count() == 0 {
+        return Err(ClassifyError::EmptyData);
+    }
+    Ok(digest_to_quantile_classification(num_bins, &mut digest))
+}
+
+/// Builds a Classification from the estimated quantiles of a non-empty TDigest, with bin counts estimated from its distribution
+fn digest_to_quantile_classification(num_bins: usize, digest: &mut TDigest) -> Classification {
+    let (min, max) = (digest.min(), digest.max());
+    let mut breaks: Vec<f64> = vec![];
+    for i in 1..num_bins {
+        let new_break = digest.quantile(i as f64 / num_bins as f64);
+        if new_break > min && new_break < max && breaks.last().map_or(true, |brk| new_break > *brk)
+        {
+            breaks.push(new_break);
+        }
+    }
+
+    let total = digest.count();
+    let mut results: Classification = vec![];
+    let mut bin_start = min;
+    let mut counted: u64 = 0;
+    for brk in breaks {
+        let below = ((digest.cdf(brk) * total as f64).round() as u64).max(counted);
+        results.push(Bin {
+            bin_start,
+            bin_end: brk,
+            count: below - counted,
+        });
+        bin_start = brk;
+        counted = below;
+    }
+    results.push(Bin {
+        bin_start,
+        bin_end: max,
+        count: total - counted,
+    });
+    results
+}
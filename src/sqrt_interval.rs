@@ -0,0 +1,114 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::utilities::Classification;
+use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, validate_num_bins};
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+use num_traits::ToPrimitive;
+
+/// Returns a Classification object following the Square Root Interval Breaks algorithm given the desired number of bins and one-dimensional data
+///
+/// # Arguments
+///
+/// * `num_bins` - An integer (usize) representing the desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns errors in the same cases as get_sqrt_interval_breaks
+/// * If all data points share the same value, no breaks are generated and the result is a single bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_sqrt_interval_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<u32> = vec![0, 1, 4, 9, 16, 25, 36];
+/// let num_bins = 3;
+///
+/// let result: Classification = get_sqrt_interval_classification(num_bins, &data).unwrap();
+/// let expected: Classification = vec![
+///     Bin{bin_start: 0.0, bin_end: 4.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 16.0, count: 2},
+///     Bin{bin_start: 16.0, bin_end: 36.0, count: 3}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn get_sqrt_interval_classification<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    let breaks: Vec<f64> = get_sqrt_interval_breaks(num_bins, data)?;
+    Ok(breaks_to_classification(&breaks, data))
+}
+
+/// Returns a vector of breaks spaced equally in square root space given the desired number of bins and a dataset
+///
+/// The range from the square root of the minimum to the square root of the maximum is split into equal intervals, whose bounds are squared back into the data's units. Bins widen toward the maximum, though less quickly than logarithmic intervals, which suits data such as population densities where extremes should be de-emphasized gently
+///
+/// # Arguments
+///
+/// * `num_bins` - The desired number of bins
+/// * `data` - A reference to a collection of unsorted data points to generate breaks for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::ZeroBins if num_bins is 0
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+/// * Returns ClassifyError::NegativeForSqrt if any data point is less than zero, while zeros are allowed
+/// * If all data points share the same value, no breaks are generated
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_sqrt_interval_breaks;
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![0.0, 1.0, 4.0, 9.0, 16.0, 25.0, 36.0];
+///
+/// assert_eq!(get_sqrt_interval_breaks(3, &data), Ok(vec![4.0, 16.0]));
+/// assert_eq!(get_sqrt_interval_breaks(3, &[-1.0, 1.0]), Err(ClassifyError::NegativeForSqrt));
+/// ```
+pub fn get_sqrt_interval_breaks<T: ToPrimitive>(
+    num_bins: usize,
+    data: &[T],
+) -> Result<Vec<f64>, ClassifyError> {
+    validate_num_bins(num_bins)?;
+    validate_data(data)?;
+    let data = to_vec_f64(data);
+
+    let mut min_value = data[0];
+    let mut max_value = data[0];
+    for item in &data {
+        if *item < 0.0 {
+            return Err(ClassifyError::NegativeForSqrt);
+        }
+        if *item < min_value {
+            min_value = *item;
+        } else if *item > max_value {
+            max_value = *item;
+        }
+    }
+
+    let mut result: Vec<f64> = vec![];
+    if min_value == max_value {
+        return Ok(result);
+    }
+
+    let sqrt_min = min_value.sqrt();
+    let sqrt_max = max_value.sqrt();
+    for i in 1..num_bins {
+        let sqrt_break = sqrt_min + (sqrt_max - sqrt_min) * (i as f64 / num_bins as f64);
+        result.push(sqrt_break * sqrt_break);
+    }
+
+    Ok(result)
+}
@@ -1,5 +1,10 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::ClassifyError;
+use crate::summary::DataSummary;
 use crate::utilities::Classification;
 use crate::utilities::{breaks_to_classification, to_vec_f64};
+use crate::utilities::{validate_data, MAX_INTERVAL_BINS};
 use num_traits::ToPrimitive;
 
 /// Returns a Classification object following the Standard Deviation Breaks algorithm given the desired bin size as a proportion of a standard deviation and one-dimensional data
@@ -8,11 +13,13 @@ use num_traits::ToPrimitive;
 /// # Arguments
 ///
 /// * `bin_size` - A float representing the proportion of a standard deviation each bin should encompass
-/// * `data` - A reference to a vector of unsorted data points (f64) to generate a Classification for
+/// * `data` - A reference to a collection of unsorted data points (f64) to generate a Classification for
 ///
 /// # Edge cases
 ///
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Breaks that fall on or outside the minimum/maximum of the data are dropped so that no empty leading or trailing bins are produced
+/// * Panics if bin_size is so small that the data's range would span more than 1,000,000 bins (use try_get_st_dev_classification to get an error instead)
 ///
 /// # Examples
 ///
@@ -41,6 +48,45 @@ pub fn get_st_dev_classification<T: ToPrimitive, S: ToPrimitive>(
     breaks_to_classification(&breaks, data)
 }
 
+/// Returns a Classification object following the Standard Deviation Breaks algorithm, returning an error instead of panicking if bin_size is not positive or data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `bin_size` - A float representing the proportion of a standard deviation each bin should encompass
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::NonPositiveInterval if bin_size is zero, negative, or not a number
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+/// * Returns ClassifyError::TooManyBins if bin_size is so small that the data's range would span more than 1,000,000 bins
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_st_dev_classification, try_get_st_dev_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_st_dev_classification(1.0, &data).unwrap() == get_st_dev_classification(1.0, &data));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_st_dev_classification(1.0, &empty), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_st_dev_classification<T: ToPrimitive, S: ToPrimitive>(
+    bin_size: S,
+    data: &[T],
+) -> Result<Classification, ClassifyError> {
+    if !bin_size.to_f64().map_or(false, |size| size > 0.0) {
+        return Err(ClassifyError::NonPositiveInterval);
+    }
+    validate_data(data)?;
+    let (breaks, _stats) = st_dev_breaks_and_stats(bin_size, data)?;
+    Ok(breaks_to_classification(&breaks, data))
+}
+
 /// Returns a vector of breaks generated through the Standard Deviation Breaks algorithm given the desired bin size as a proportion of a standard deviation and a dataset
 /// Note: This algorithm calculates Standard Deviation with Bessel's correction
 ///
@@ -52,6 +98,8 @@ pub fn get_st_dev_classification<T: ToPrimitive, S: ToPrimitive>(
 /// # Edge cases
 ///
 /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Breaks that fall on or outside the minimum/maximum of the data are dropped so that no empty leading or trailing bins are produced
+/// * Panics if bin_size is so small that the data's range would span more than 1,000,000 bins (use try_get_st_dev_classification to get an error instead)
 ///
 /// # Examples
 ///
@@ -66,42 +114,101 @@ pub fn get_st_dev_classification<T: ToPrimitive, S: ToPrimitive>(
 /// assert_eq!(result, vec![0.41987655026535653, 1.5, 2.5801234497346437]);
 /// ```
 pub fn get_st_dev_breaks<T: ToPrimitive, S: ToPrimitive>(bin_size: S, data: &[T]) -> Vec<f64> {
+    let (breaks, _stats) =
+        st_dev_breaks_and_stats(bin_size, data).unwrap_or_else(|err| panic!("{}", err));
+    breaks
+}
+
+/// Returns a Classification object following the Standard Deviation Breaks algorithm along with the mean and standard deviation used to generate it
+/// Note: This algorithm calculates Standard Deviation with Bessel's correction
+///
+/// # Arguments
+///
+/// * `bin_size` - A float representing the proportion of a standard deviation each bin should encompass
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Breaks that fall on or outside the minimum/maximum of the data are dropped so that no empty leading or trailing bins are produced
+/// * Panics if bin_size is so small that the data's range would span more than 1,000,000 bins (use try_get_st_dev_classification to get an error instead)
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_st_dev_classification_with_stats;
+/// use classify::{Classification, StDevStats};
+///
+/// let data: Vec<f32> = vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0];
+/// let bin_size = 1.0;
+///
+/// let (result, stats): (Classification, StDevStats) = get_st_dev_classification_with_stats(bin_size, &data);
+///
+/// assert_eq!(result.len(), 4);
+/// assert_eq!(stats.mean, 1.5);
+/// assert_eq!(stats.st_dev, 1.0801234497346435);
+/// ```
+pub fn get_st_dev_classification_with_stats<T: ToPrimitive, S: ToPrimitive>(
+    bin_size: S,
+    data: &[T],
+) -> (Classification, StDevStats) {
+    let (breaks, stats) =
+        st_dev_breaks_and_stats(bin_size, data).unwrap_or_else(|err| panic!("{}", err));
+    (breaks_to_classification(&breaks, data), stats)
+}
+
+/// Represents the mean and standard deviation (with Bessel's correction) from which a set of Standard Deviation Breaks was generated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StDevStats {
+    pub mean: f64,
+    pub st_dev: f64,
+}
+
+/// Generates Standard Deviation Breaks and returns them along with the statistics used to place them, or ClassifyError::TooManyBins if the data's range would span more than MAX_INTERVAL_BINS bins
+fn st_dev_breaks_and_stats<T: ToPrimitive, S: ToPrimitive>(
+    bin_size: S,
+    data: &[T],
+) -> Result<(Vec<f64>, StDevStats), ClassifyError> {
     let bin_size = bin_size.to_f64().unwrap();
     let data = to_vec_f64(data);
 
-    let mut min_value = data[0];
-    let mut max_value = data[0];
-    let mut mean = 0.0;
-    for item in &data {
-        mean += item;
-        if *item < min_value {
-            min_value = *item;
-        } else if *item > max_value {
-            max_value = *item
-        }
-    }
-    mean /= data.len() as f64;
-
-    let st_dev = calc_st_dev(&data);
+    let summary = DataSummary::from_data(&data);
+    let (min_value, max_value, mean) = (summary.min, summary.max, summary.mean);
+    let st_dev = summary.std_dev();
     let new_dev = st_dev * bin_size;
+    // A deviation that underflows to zero (such as for subnormal data) would place breaks without end
+    if new_dev.is_nan() || new_dev <= 0.0 {
+        return Ok((vec![], StDevStats { mean, st_dev }));
+    }
 
-    let devs_below_mean = ((mean - min_value) / new_dev) as isize;
-    let devs_above_mean = ((max_value - mean) / new_dev) as isize;
+    let devs_below_mean = (mean - min_value) / new_dev;
+    let devs_above_mean = (max_value - mean) / new_dev;
+    // A tiny bin size would otherwise place billions of breaks
+    if !(devs_below_mean + devs_above_mean).is_finite()
+        || devs_below_mean + devs_above_mean > MAX_INTERVAL_BINS as f64
+    {
+        return Err(ClassifyError::TooManyBins);
+    }
 
     let mut breaks: Vec<f64> = vec![];
-    for i in -devs_below_mean..(devs_above_mean + 1) {
-        breaks.push(mean + (i as f64) * new_dev);
+    for i in -(devs_below_mean as isize)..=(devs_above_mean as isize) {
+        let new_break = mean + (i as f64) * new_dev;
+        // Breaks on or outside the data's range would only produce empty bins
+        if min_value < new_break && new_break < max_value {
+            breaks.push(new_break);
+        }
     }
 
-    breaks
+    Ok((breaks, StDevStats { mean, st_dev }))
 }
 
-/// Calculates the standard deviation of a dataset using Bessel's correction
+/// Calculates the standard deviation of a dataset using Bessel's correction, in two passes as a reference for DataSummary
 ///
 /// # Arguments
 ///
 /// * `data` - A reference to a collection containing data to calculate standard deviation for
-pub fn calc_st_dev(data: &Vec<f64>) -> f64 {
+#[cfg(test)]
+pub fn calc_st_dev(data: &[f64]) -> f64 {
     let mut mean: f64 = 0.0;
     for val in data {
         mean += *val
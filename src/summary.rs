@@ -0,0 +1,190 @@
+use num_traits::ToPrimitive;
+
+use crate::percentile::{calc_percentile, PercentileInterpolation};
+use crate::utilities::to_vec_f64;
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+
+/// Holds basic statistics of a dataset computed in a single pass, so that they can be calculated once and shared instead of rescanning the data for each
+///
+/// The mean and variance are accumulated with Welford's algorithm, which stays accurate for large values with a small spread, and the third and fourth central moments behind the skewness and kurtosis are updated alongside them
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataSummary {
+    /// The number of data points
+    pub count: usize,
+    /// The smallest data point
+    pub min: f64,
+    /// The largest data point
+    pub max: f64,
+    /// The arithmetic mean of the data points
+    pub mean: f64,
+    /// The sample variance of the data points (using Bessel's correction)
+    pub variance: f64,
+    /// The skewness of the data points (the third standardized moment), positive for a long right tail
+    pub skewness: f64,
+    /// The excess kurtosis of the data points (the fourth standardized moment minus 3), 0 for normally distributed data and -1.2 for uniformly distributed data
+    pub kurtosis: f64,
+}
+
+impl DataSummary {
+    /// Returns a DataSummary of a dataset
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A reference to a collection of unsorted data points to summarize
+    ///
+    /// # Edge Cases
+    ///
+    /// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+    /// * For empty data, the count is 0 and every other field is NaN, for a single data point the variance is NaN, and for constant data the skewness and kurtosis are NaN
+    /// * NaN data points make the min, max, mean, and variance NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use classify::DataSummary;
+    ///
+    /// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0];
+    /// let summary = DataSummary::from_data(&data);
+    ///
+    /// assert_eq!(summary.count, 4);
+    /// assert_eq!((summary.min, summary.max), (1.0, 5.0));
+    /// assert_eq!(summary.mean, 3.0);
+    /// assert_eq!(summary.variance, 10.0 / 3.0);
+    /// ```
+    pub fn from_data<T: ToPrimitive>(data: &[T]) -> DataSummary {
+        let data = to_vec_f64(data);
+        if data.is_empty() {
+            return DataSummary {
+                count: 0,
+                min: f64::NAN,
+                max: f64::NAN,
+                mean: f64::NAN,
+                variance: f64::NAN,
+                skewness: f64::NAN,
+                kurtosis: f64::NAN,
+            };
+        }
+
+        let mut min = data[0];
+        let mut max = data[0];
+        let mut mean = 0.0;
+        let mut ssd = 0.0;
+        let mut m3 = 0.0;
+        let mut m4 = 0.0;
+        for (i, val) in data.iter().enumerate() {
+            // f64::min and f64::max ignore NaN, so a NaN seen earlier has to be kept explicitly
+            if val.is_nan() {
+                min = f64::NAN;
+                max = f64::NAN;
+            } else if !min.is_nan() {
+                min = min.min(*val);
+                max = max.max(*val);
+            }
+            let n = (i + 1) as f64;
+            let delta = val - mean;
+            let delta_n = delta / n;
+            let term = delta * delta_n * (n - 1.0);
+            m4 += term * delta_n * delta_n * (n * n - 3.0 * n + 3.0)
+                + 6.0 * delta_n * delta_n * ssd
+                - 4.0 * delta_n * m3;
+            m3 += term * delta_n * (n - 2.0) - 3.0 * delta_n * ssd;
+            mean += delta_n;
+            ssd += delta * (val - mean);
+        }
+        let num_vals = data.len() as f64;
+
+        DataSummary {
+            count: data.len(),
+            min,
+            max,
+            mean,
+            variance: ssd / (num_vals - 1.0),
+            skewness: num_vals.sqrt() * m3 / ssd.powf(1.5),
+            kurtosis: num_vals * m4 / (ssd * ssd) - 3.0,
+        }
+    }
+
+    /// Returns the sample standard deviation of the data points (using Bessel's correction)
+    pub fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+}
+
+/// Represents a statistical rule for choosing the number of bins of a histogram-style classification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinCountRule {
+    /// Sturges' rule: ceil(log2(n)) + 1 bins
+    Sturges,
+    /// Scott's rule: bins of width 3.49 * σ * n^(-1/3), with σ the sample standard deviation
+    Scott,
+    /// The Freedman–Diaconis rule: bins of width 2 * IQR * n^(-1/3), which is less sensitive to outliers than Scott's rule
+    FreedmanDiaconis,
+    /// The square-root choice: ceil(sqrt(n)) bins
+    SquareRoot,
+}
+
+/// Returns the number of bins a statistical rule suggests for a dataset, for use with get_equal_interval_classification
+///
+/// For the rules that give a bin width (Scott and Freedman–Diaconis), the number of bins is the data's range divided by that width, rounded up
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points
+/// * `rule` - The BinCountRule used to choose the number of bins
+///
+/// # Edge Cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * At least 1 is always returned, including for empty data, constant data, and data whose standard deviation or interquartile range is zero or NaN
+/// * The Scott and Freedman–Diaconis rules return 1 for data containing NaN or infinite values, as its range cannot be divided into bins of finite width
+///
+/// # Examples
+///
+/// ```
+/// use classify::{suggest_bin_count, BinCountRule};
+///
+/// let data: Vec<f64> = (0..100).map(|i| i as f64).collect();
+///
+/// assert_eq!(suggest_bin_count(&data, BinCountRule::Sturges), 8);
+/// assert_eq!(suggest_bin_count(&data, BinCountRule::SquareRoot), 10);
+/// assert_eq!(suggest_bin_count(&data, BinCountRule::Scott), 5);
+/// assert_eq!(suggest_bin_count(&data, BinCountRule::FreedmanDiaconis), 5);
+/// ```
+pub fn suggest_bin_count<T: ToPrimitive>(data: &[T], rule: BinCountRule) -> usize {
+    let summary = DataSummary::from_data(data);
+    let num_vals = summary.count as f64;
+    if summary.count == 0 {
+        return 1;
+    }
+
+    let count = match rule {
+        BinCountRule::Sturges => num_vals.log2().ceil() + 1.0,
+        BinCountRule::SquareRoot => num_vals.sqrt().ceil(),
+        BinCountRule::Scott | BinCountRule::FreedmanDiaconis => {
+            // A range that is NaN or infinite cannot be divided into bins (and NaN cannot be sorted)
+            let range = summary.max - summary.min;
+            if !range.is_finite() {
+                return 1;
+            }
+            let spread = if rule == BinCountRule::Scott {
+                3.49 * summary.std_dev()
+            } else {
+                let mut sorted_data = to_vec_f64(data);
+                sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let q1 = calc_percentile(25.0, &sorted_data, PercentileInterpolation::Linear);
+                let q3 = calc_percentile(75.0, &sorted_data, PercentileInterpolation::Linear);
+                2.0 * (q3 - q1)
+            };
+            let width = spread * num_vals.powf(-1.0 / 3.0);
+            (range / width).ceil()
+        }
+    };
+
+    if count.is_finite() && count >= 1.0 {
+        count as usize
+    } else {
+        1
+    }
+}
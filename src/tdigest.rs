@@ -0,0 +1,182 @@
+use alloc::{vec, vec::Vec};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+#[allow(unused_imports)] // Shadowed by std's float methods when a dependency links std
+use num_traits::Float;
+
+use crate::error::ClassifyError;
+
+/// The compression used for approximate quantiles unless one is given
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+/// Represents a cluster of nearby data points in a TDigest by their mean and number
+#[derive(Debug, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Summarizes a stream of data points in bounded memory so that its quantiles can be estimated, following the merging t-digest of Dunning and Ertl (2019)
+///
+/// Data points are buffered and periodically merged into centroids whose sizes are limited by the arcsine scale function, so that centroids near the tails stay small (keeping extreme quantiles accurate) while those near the median grow larger. The number of centroids is roughly proportional to the compression and independent of the number of data points
+#[derive(Debug, Clone)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    buffer: Vec<f64>,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Creates an empty TDigest, with compressions below 10 raised to 10
+    pub fn new(compression: f64) -> TDigest {
+        let compression = if compression >= 10.0 {
+            compression
+        } else {
+            10.0
+        };
+        TDigest {
+            compression,
+            centroids: vec![],
+            buffer: vec![],
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Adds a data point to the TDigest, or returns ClassifyError::NonFinite if it is NaN or infinite
+    pub fn push(&mut self, val: f64) -> Result<(), ClassifyError> {
+        if !val.is_finite() {
+            return Err(ClassifyError::NonFinite);
+        }
+        self.buffer.push(val);
+        self.count += 1;
+        self.min = self.min.min(val);
+        self.max = self.max.max(val);
+        if self.buffer.len() as f64 >= 5.0 * self.compression {
+            self.compress();
+        }
+        Ok(())
+    }
+
+    /// Returns the number of data points added to the TDigest
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the smallest data point added to the TDigest (infinity if there are none)
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the largest data point added to the TDigest (negative infinity if there are none)
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Merges the buffered data points into the centroids
+    pub fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut clusters: Vec<Centroid> = self.centroids.clone();
+        clusters.extend(self.buffer.iter().map(|val| Centroid {
+            mean: *val,
+            weight: 1.0,
+        }));
+        clusters.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap());
+        self.buffer.clear();
+
+        let total = self.count as f64;
+        let mut merged: Vec<Centroid> = vec![];
+        let mut current = clusters[0];
+        let mut weight_so_far = 0.0;
+        let mut weight_limit = total * self.max_quantile(0.0);
+        for cluster in clusters.into_iter().skip(1) {
+            if weight_so_far + current.weight + cluster.weight <= weight_limit {
+                let weight = current.weight + cluster.weight;
+                current.mean += (cluster.mean - current.mean) * cluster.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                merged.push(current);
+                weight_limit = total * self.max_quantile(weight_so_far / total);
+                current = cluster;
+            }
+        }
+        merged.push(current);
+
+        self.centroids = merged;
+    }
+
+    /// Returns the highest quantile a centroid starting at quantile q may reach, which is one unit further along the arcsine scale function k(q) = compression / (2π) * asin(2q - 1)
+    fn max_quantile(&self, q: f64) -> f64 {
+        let scale = self.compression / (2.0 * core::f64::consts::PI);
+        let k = scale * (2.0 * q - 1.0).clamp(-1.0, 1.0).asin() + 1.0;
+        let angle = (k / scale).min(core::f64::consts::FRAC_PI_2);
+        (angle.sin() + 1.0) / 2.0
+    }
+
+    /// Returns the points (value, rank) between which values and ranks are interpolated: the minimum at rank 0, the mean of each centroid at the rank of its middle, and the maximum at the number of data points
+    fn knots(&mut self) -> Vec<(f64, f64)> {
+        self.compress();
+        let mut knots: Vec<(f64, f64)> = vec![(self.min, 0.0)];
+        let mut rank = 0.0;
+        for centroid in self.centroids.iter() {
+            knots.push((centroid.mean, rank + centroid.weight / 2.0));
+            rank += centroid.weight;
+        }
+        knots.push((self.max, rank));
+        knots
+    }
+
+    /// Returns the estimated value below which a fraction q (0 to 1, clamped) of the data points fall, or NaN if the TDigest is empty
+    pub fn quantile(&mut self, q: f64) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        let knots = self.knots();
+        let rank = q.clamp(0.0, 1.0) * self.count as f64;
+        for pair in knots.windows(2) {
+            let ((lower_val, lower_rank), (upper_val, upper_rank)) = (pair[0], pair[1]);
+            if rank <= upper_rank {
+                if upper_rank <= lower_rank {
+                    return lower_val;
+                }
+                return lower_val
+                    + (upper_val - lower_val) * (rank - lower_rank) / (upper_rank - lower_rank);
+            }
+        }
+        self.max
+    }
+
+    /// Returns the estimated fraction of the data points that fall below a value, or NaN if the TDigest is empty
+    pub fn cdf(&mut self, val: f64) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        if val <= self.min {
+            return 0.0;
+        }
+        if val > self.max {
+            return 1.0;
+        }
+        let knots = self.knots();
+        for pair in knots.windows(2) {
+            let ((lower_val, lower_rank), (upper_val, upper_rank)) = (pair[0], pair[1]);
+            if val <= upper_val {
+                if upper_val <= lower_val {
+                    return lower_rank / self.count as f64;
+                }
+                let rank = lower_rank
+                    + (upper_rank - lower_rank) * (val - lower_val) / (upper_val - lower_val);
+                return rank / self.count as f64;
+            }
+        }
+        1.0
+    }
+}
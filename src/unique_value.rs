@@ -0,0 +1,203 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::ToPrimitive;
+
+use crate::error::ClassifyError;
+use crate::utilities::validate_data;
+use crate::utilities::{create_unique_val_mapping, to_vec_f64};
+use crate::utilities::{Bin, Classification, UniqueVal};
+
+/// Returns a Classification object with one bin per unique value in one-dimensional data, optionally capping the number of bins
+///
+/// Each bin starts and ends on the value it represents. If `max_classes` is given and there are more unique values than that, the `max_classes - 1` most frequent values keep their own bins and the rest are lumped into a final "other" bin spanning from the smallest to the largest lumped value
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `max_classes` - An optional cap on the number of bins (including the "other" bin)
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Bins for individual values are sorted by value, with the "other" bin (if any) always last
+/// * When several values are equally frequent, the smaller values keep their own bins first
+/// * A `max_classes` of 0 is treated as 1, which lumps every value into the "other" bin
+/// * The "other" bin's range can overlap the bins of kept values (as in the example below, where it spans 3), so ClassificationExt::classify and classify_val can place a kept value in the "other" bin or a lumped value in a kept bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_unique_value_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<u8> = vec![1, 1, 1, 2, 3, 3, 4];
+///
+/// let result: Classification = get_unique_value_classification(&data, None);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 1.0, count: 3},
+///     Bin{bin_start: 2.0, bin_end: 2.0, count: 1},
+///     Bin{bin_start: 3.0, bin_end: 3.0, count: 2},
+///     Bin{bin_start: 4.0, bin_end: 4.0, count: 1}
+/// ];
+/// assert!(result == expected);
+///
+/// let result: Classification = get_unique_value_classification(&data, Some(3));
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 1.0, count: 3},
+///     Bin{bin_start: 3.0, bin_end: 3.0, count: 2},
+///     Bin{bin_start: 2.0, bin_end: 4.0, count: 2}
+/// ];
+/// assert!(result == expected);
+/// ```
+pub fn get_unique_value_classification<T: ToPrimitive>(
+    data: &[T],
+    max_classes: Option<usize>,
+) -> Classification {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+    let num_unique_vals = unique_val_map.len();
+
+    let max_classes = core::cmp::max(max_classes.unwrap_or(num_unique_vals), 1);
+    let num_kept = if num_unique_vals <= max_classes {
+        num_unique_vals
+    } else {
+        max_classes - 1
+    };
+
+    top_n_unique_bins(num_kept, &unique_val_map).0
+}
+
+/// Returns a Classification object with one bin for each of the n most frequent unique values in one-dimensional data and a final "other" bin for all remaining values, along with the values that were lumped into the "other" bin
+///
+/// This suits categorical data with a long tail of rare values. The "other" bin spans from the smallest to the largest lumped value, so it can overlap the bins of kept values and should be looked up through the returned values rather than by range
+///
+/// # Arguments
+///
+/// * `n` - The number of most frequent unique values to keep in their own bins
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Bins for individual values are sorted by value, with the "other" bin (if any) always last, and the lumped values are sorted ascending
+/// * When several values tie for the nth highest frequency, the smaller values keep their own bins first
+/// * If there are at most n unique values, every value keeps its own bin and there is no "other" bin, while an n of 0 lumps every value into the "other" bin
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_top_n_unique_classification;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<u8> = vec![1, 1, 1, 2, 3, 3, 4, 5, 5];
+///
+/// let (result, other_vals) = get_top_n_unique_classification(2, &data);
+/// let expected: Classification = vec![
+///     Bin{bin_start: 1.0, bin_end: 1.0, count: 3},
+///     Bin{bin_start: 3.0, bin_end: 3.0, count: 2},
+///     Bin{bin_start: 2.0, bin_end: 5.0, count: 4}
+/// ];
+///
+/// assert!(result == expected);
+/// assert_eq!(other_vals, vec![2.0, 4.0, 5.0]);
+/// ```
+pub fn get_top_n_unique_classification<T: ToPrimitive>(
+    n: usize,
+    data: &[T],
+) -> (Classification, Vec<f64>) {
+    let data = to_vec_f64(data);
+
+    let mut sorted_data: Vec<f64> = data.to_vec();
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut unique_val_map: Vec<UniqueVal> = vec![];
+    create_unique_val_mapping(&mut unique_val_map, &sorted_data);
+
+    top_n_unique_bins(n, &unique_val_map)
+}
+
+/// Returns one bin per unique value for the num_kept most frequent unique values (sorted by value) and, if any values remain, a final "other" bin lumping them together, along with the lumped values (sorted, ascending)
+fn top_n_unique_bins(num_kept: usize, unique_val_map: &[UniqueVal]) -> (Classification, Vec<f64>) {
+    let num_unique_vals = unique_val_map.len();
+    let num_kept = core::cmp::min(num_kept, num_unique_vals);
+
+    // Indices of the unique values ordered from most to least frequent, breaking ties by value
+    let mut by_frequency: Vec<usize> = (0..num_unique_vals).collect();
+    by_frequency.sort_by(|a, b| {
+        let count_a = unique_val_map[*a].last - unique_val_map[*a].first;
+        let count_b = unique_val_map[*b].last - unique_val_map[*b].first;
+        count_b.cmp(&count_a).then(a.cmp(b))
+    });
+    let mut kept: Vec<usize> = by_frequency[..num_kept].to_vec();
+    kept.sort_unstable();
+
+    let mut results: Classification = vec![];
+    for idx in &kept {
+        let item = &unique_val_map[*idx];
+        results.push(Bin {
+            bin_start: item.val,
+            bin_end: item.val,
+            count: (item.last - item.first + 1) as u64,
+        });
+    }
+
+    let mut lumped: Vec<usize> = by_frequency[num_kept..].to_vec();
+    if lumped.is_empty() {
+        return (results, vec![]);
+    }
+    lumped.sort_unstable();
+
+    let mut other = Bin {
+        bin_start: f64::INFINITY,
+        bin_end: f64::NEG_INFINITY,
+        count: 0,
+    };
+    for idx in &lumped {
+        let item = &unique_val_map[*idx];
+        other.bin_start = other.bin_start.min(item.val);
+        other.bin_end = other.bin_end.max(item.val);
+        other.count += (item.last - item.first + 1) as u64;
+    }
+    results.push(other);
+
+    let other_vals: Vec<f64> = lumped.iter().map(|idx| unique_val_map[*idx].val).collect();
+    (results, other_vals)
+}
+
+/// Returns a Classification object following the Unique Value algorithm, returning an error instead of panicking if data contains no values or values that are not finite
+///
+/// # Arguments
+///
+/// * `data` - A reference to a collection of unsorted data points to generate a Classification for
+/// * `max_classes` - An optional cap on the number of bins (including the "other" bin)
+///
+/// # Edge cases
+///
+/// * Inputting large u64/i64 data (near their max values) will result in loss of precision because data is being cast to f64
+/// * Returns ClassifyError::EmptyData if data contains no values
+/// * Returns ClassifyError::NonFinite if data contains NaN or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use classify::{get_unique_value_classification, try_get_unique_value_classification};
+/// use classify::ClassifyError;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 9.0];
+/// assert!(try_get_unique_value_classification(&data, None).unwrap() == get_unique_value_classification(&data, None));
+///
+/// let empty: Vec<f64> = vec![];
+/// assert_eq!(try_get_unique_value_classification(&empty, None), Err(ClassifyError::EmptyData));
+/// ```
+pub fn try_get_unique_value_classification<T: ToPrimitive>(
+    data: &[T],
+    max_classes: Option<usize>,
+) -> Result<Classification, ClassifyError> {
+    validate_data(data)?;
+    Ok(get_unique_value_classification(data, max_classes))
+}
@@ -1,36 +1,157 @@
-use num_traits::ToPrimitive;
+use alloc::{vec, vec::Vec};
+use core::hash::{Hash, Hasher};
+
+use num_traits::{Float, ToPrimitive};
+
+use crate::error::ClassifyError;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Represents a unique value found within a sorted dataset along with the indices of its first and last occurrences in the dataset
-pub struct UniqueVal {
-    pub val: f64,
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: ToPrimitive", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct UniqueVal<T = f64> {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_finite"))]
+    pub val: T,
     pub first: usize,
     pub last: usize,
 }
 
 /// Represents a single bin in a classification, including the bin's lowest (inclusive) and highest (exclusive) values and the number of points within it
-pub struct Bin {
-    pub bin_start: f64,
-    pub bin_end: f64,
+///
+/// Bins hold f64 values unless produced by one of the `_float` functions, which keep the data's own floating point type
+///
+/// Open-ended outer bins (see ClassificationExt::with_open_ends) start at negative infinity or end at positive infinity. With the serde feature, such bounds are serialized as null, and a null bin_start or bin_end is deserialized as negative or positive infinity respectively
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: ToPrimitive",
+        deserialize = "T: Deserialize<'de> + Float"
+    ))
+)]
+pub struct Bin<T = f64> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_finite",
+            deserialize_with = "deserialize_or_neg_infinity"
+        )
+    )]
+    pub bin_start: T,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_finite",
+            deserialize_with = "deserialize_or_infinity"
+        )
+    )]
+    pub bin_end: T,
     pub count: u64,
 }
 
-impl PartialEq for Bin {
+/// Serializes finite values as numbers and NaN or infinite values as null (none), since formats such as JSON cannot represent them
+#[cfg(feature = "serde")]
+fn serialize_finite<T: ToPrimitive, S: Serializer>(
+    val: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match val.to_f64() {
+        Some(val) if val.is_finite() => serializer.serialize_f64(val),
+        _ => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes a number, reading null (as written for non-finite bin starts) as negative infinity
+#[cfg(feature = "serde")]
+pub fn deserialize_or_neg_infinity<'de, T: Deserialize<'de> + Float, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_else(T::neg_infinity))
+}
+
+/// Deserializes a number, reading null (as written for non-finite bin ends) as positive infinity
+#[cfg(feature = "serde")]
+pub fn deserialize_or_infinity<'de, T: Deserialize<'de> + Float, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_else(T::infinity))
+}
+
+/// Compares bins structurally: two bins are equal if their bounds hold the same numbers and their counts match
+///
+/// Unlike comparing the bounds directly with `==`, NaN bounds are equal to each other, which makes the comparison an equivalence relation so that Bin can implement Eq and Hash (for example, to be used as a HashMap key). As with `==`, -0.0 and 0.0 are equal
+impl<T: Float> PartialEq for Bin<T> {
     fn eq(&self, other: &Self) -> bool {
-        let starts_eq: bool = self.bin_start == other.bin_start;
-        let ends_eq: bool = self.bin_end == other.bin_end;
+        let starts_eq: bool = float_eq(self.bin_start, other.bin_start);
+        let ends_eq: bool = float_eq(self.bin_end, other.bin_end);
         let counts_eq: bool = self.count == other.count;
         starts_eq && ends_eq && counts_eq
     }
 }
 
+impl<T: Float> Eq for Bin<T> {}
+
+/// Hashes the bit patterns of a bin's bounds, with -0.0 normalized to 0.0 and every NaN to a single NaN, along with its count, so that bins that are equal hash to the same value
+impl<T: Float> Hash for Bin<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        float_hash_key(self.bin_start).hash(state);
+        float_hash_key(self.bin_end).hash(state);
+        self.count.hash(state);
+    }
+}
+
+/// Compares two floating point values, treating NaN as equal to itself
+fn float_eq<T: Float>(a: T, b: T) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+/// Decodes a floating point value into its mantissa, exponent, and sign after normalizing -0.0 to 0.0 and every NaN to T::nan(), so that values that are equal under float_eq have the same key
+fn float_hash_key<T: Float>(val: T) -> (u64, i16, i8) {
+    if val.is_nan() {
+        T::nan().integer_decode()
+    } else if val == T::zero() {
+        T::zero().integer_decode()
+    } else {
+        val.integer_decode()
+    }
+}
+
 /// Represents a full classification, which is a collection of Bin objects
-pub type Classification = Vec<Bin>;
+///
+/// As a vector of bins, a Classification can be iterated over directly (by value or by reference) and used with iterator adapters
+///
+/// # Examples
+///
+/// ```
+/// use classify::get_equal_interval_classification;
+/// use classify::Classification;
+///
+/// let data: Vec<f64> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let class: Classification = get_equal_interval_classification(2, &data);
+///
+/// let mut total = 0;
+/// for bin in &class {
+///     total += bin.count;
+/// }
+/// assert_eq!(total, 6);
+///
+/// let widths: Vec<f64> = class.iter().map(|bin| bin.bin_end - bin.bin_start).collect();
+/// assert_eq!(widths, vec![3.5, 3.5]);
+/// ```
+pub type Classification<T = f64> = Vec<Bin<T>>;
 
 /// Translates generic numeric vectors to Vec<f64> using the ToPrimitive trait from the num crate
 ///
 /// # Arguments
 ///
-/// * `data` - A reference to a vector of generic type T where T implements the ToPrimitive trait
+/// * `data` - A reference to a collection of generic type T where T implements the ToPrimitive trait
 pub fn to_vec_f64<T: ToPrimitive>(data: &[T]) -> Vec<f64> {
     let mut result: Vec<f64> = vec![];
     for item in data {
@@ -39,13 +160,69 @@ pub fn to_vec_f64<T: ToPrimitive>(data: &[T]) -> Vec<f64> {
     result
 }
 
+/// Represents how data points that are not finite (NaN or infinite) are handled before classifying
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Remove non-finite data points and classify the rest
+    Drop,
+    /// Fail with ClassifyError::NonFinite if any data point is not finite (the default)
+    Error,
+}
+
+impl Default for NanPolicy {
+    fn default() -> Self {
+        NanPolicy::Error
+    }
+}
+
+/// Applies a NanPolicy to a vector of data points, returning the data points to classify
+pub fn apply_nan_policy(data: Vec<f64>, nan_policy: NanPolicy) -> Result<Vec<f64>, ClassifyError> {
+    match nan_policy {
+        NanPolicy::Drop => Ok(data.into_iter().filter(|val| val.is_finite()).collect()),
+        NanPolicy::Error => {
+            if data.iter().all(|val| val.is_finite()) {
+                Ok(data)
+            } else {
+                Err(ClassifyError::NonFinite)
+            }
+        }
+    }
+}
+
+/// The largest number of bins that the methods placing breaks a fixed width apart (Defined Interval and Standard Deviation Breaks) may split the data's range into before returning ClassifyError::TooManyBins
+pub const MAX_INTERVAL_BINS: usize = 1_000_000;
+
+/// Checks that a dataset can be classified, returning ClassifyError::EmptyData if it contains no values or ClassifyError::NonFinite if any value is NaN or infinite
+pub fn validate_data<T: ToPrimitive>(data: &[T]) -> Result<(), ClassifyError> {
+    if data.is_empty() {
+        return Err(ClassifyError::EmptyData);
+    }
+    for item in data {
+        if !item.to_f64().unwrap_or(f64::NAN).is_finite() {
+            return Err(ClassifyError::NonFinite);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a requested number of bins is at least 1, returning ClassifyError::ZeroBins otherwise
+pub fn validate_num_bins(num_bins: usize) -> Result<(), ClassifyError> {
+    if num_bins == 0 {
+        return Err(ClassifyError::ZeroBins);
+    }
+    Ok(())
+}
+
 /// Populates an empty vector of UniqueVal objects for each unique value in the dataset in the format (value, first occurrence index, last occurrence index)
 ///
 /// # Arguments
 ///
 /// * `unique_val_map` - A mutable reference to an empty vector of UniqueVals
 /// * `vals` - A reference to the data (sorted, ascending) to use in populating unique_val_map
-pub fn create_unique_val_mapping(unique_val_map: &mut Vec<UniqueVal>, vals: &[f64]) {
+pub fn create_unique_val_mapping<T: PartialEq + Copy>(
+    unique_val_map: &mut Vec<UniqueVal<T>>,
+    vals: &[T],
+) {
     unique_val_map.clear();
     let mut idx: i64 = -1;
 
@@ -67,18 +244,69 @@ pub fn create_unique_val_mapping(unique_val_map: &mut Vec<UniqueVal>, vals: &[f6
             });
         }
     }
+    if let Some(final_val) = unique_val_map.last_mut() {
+        final_val.last = vals.len() - 1;
+    }
+}
+
+/// Holds cumulative sums over a set of unique values (sorted, ascending) weighted by their number of occurrences so that the sum of squared deviations of any contiguous run of unique values can be found in constant time
+pub struct UniqueValSums {
+    weights: Vec<f64>,
+    sums: Vec<f64>,
+    sq_sums: Vec<f64>,
+}
+
+impl UniqueValSums {
+    /// Builds the cumulative sums for a map of unique values to their first and last occurrences in the dataset
+    pub fn new(u_val_map: &[UniqueVal]) -> UniqueValSums {
+        let num_unique_vals = u_val_map.len();
+        // Values are centered on the first unique value to reduce cancellation when calculating deviations
+        let offset = if num_unique_vals > 0 {
+            u_val_map[0].val
+        } else {
+            0.0
+        };
+
+        let mut weights: Vec<f64> = vec![0.0; num_unique_vals + 1];
+        let mut sums: Vec<f64> = vec![0.0; num_unique_vals + 1];
+        let mut sq_sums: Vec<f64> = vec![0.0; num_unique_vals + 1];
+        for (i, item) in u_val_map.iter().enumerate() {
+            let weight = (item.last - item.first + 1) as f64;
+            let val = item.val - offset;
+            weights[i + 1] = weights[i] + weight;
+            sums[i + 1] = sums[i] + weight * val;
+            sq_sums[i + 1] = sq_sums[i] + weight * val * val;
+        }
+
+        UniqueValSums {
+            weights,
+            sums,
+            sq_sums,
+        }
+    }
+
+    /// Returns the sum of squared deviations from their mean of all occurrences of the unique values with indices in lower..upper
+    pub fn ssd(&self, lower: usize, upper: usize) -> f64 {
+        let weight = self.weights[upper] - self.weights[lower];
+        if weight == 0.0 {
+            return 0.0;
+        }
+        let sum = self.sums[upper] - self.sums[lower];
+        let sq_sum = self.sq_sums[upper] - self.sq_sums[lower];
+        (sq_sum - sum * sum / weight).max(0.0)
+    }
 }
 
 /// Adjusts break indices from unique value breaks to normal breaks, accounting for repeated values, given unique value breaks, a unique value map, and an empty vector for normal breaks
 ///
 /// # Arguments
 ///
-/// * `u_val_breaks` - A reference to a vector of uniquely valued breaks (sorted, ascending)
+/// * `u_val_breaks` - A reference to a collection of uniquely valued breaks (sorted, ascending)
 /// * `u_val_map` - A reference to a map of unique values to their first and last occurrences in the dataset
 /// * `normal_breaks` - A mutable reference to an empty vector to populate with adjusted break indices
-pub fn unique_to_normal_breaks(
-    u_val_breaks: &Vec<usize>,
-    u_val_map: &[UniqueVal],
+pub fn unique_to_normal_breaks<T>(
+    u_val_breaks: &[usize],
+    u_val_map: &[UniqueVal<T>],
     normal_breaks: &mut Vec<usize>,
 ) {
     if normal_breaks.len() != u_val_breaks.len() {
@@ -93,8 +321,13 @@ pub fn unique_to_normal_breaks(
 ///
 /// # Arguments
 ///
-/// * `breaks` - A reference to a vector of breaks (f64) generated through any classification function or manually
-/// * `data` - A reference to a vector of unsorted data points (f64) used to count the points in each bin
+/// * `breaks` - A reference to a collection of breaks (f64) generated through any classification function or manually
+/// * `data` - A reference to a collection of unsorted data points (f64) used to count the points in each bin
+///
+/// # Edge Cases
+///
+/// * Each bin contains values from its start up to but excluding its end, except for the last bin, which also contains its end, so every data point is counted exactly once when the breaks lie within the data's range
+/// * If all data points share the same value, the result is a single bin on that value containing every data point, regardless of the breaks
 ///
 /// # Examples
 ///
@@ -114,12 +347,43 @@ pub fn unique_to_normal_breaks(
 ///
 /// assert!(result == expected);
 /// ```
-pub fn breaks_to_classification<T: ToPrimitive>(breaks: &Vec<f64>, data: &[T]) -> Classification {
-    let data = to_vec_f64(data);
+pub fn breaks_to_classification<T: ToPrimitive>(breaks: &[f64], data: &[T]) -> Classification {
+    breaks_to_classification_float(breaks, &to_vec_f64(data))
+}
 
+/// Returns a Classification object given a set of breaks between bins and the original dataset, keeping the data's floating point type (such as f32) instead of converting it to f64
+///
+/// # Arguments
+///
+/// * `breaks` - A reference to a collection of breaks generated through any classification function or manually
+/// * `data` - A reference to a collection of unsorted data points used to count the points in each bin
+///
+/// # Edge Cases
+///
+/// * Bins follow the same rules as in breaks_to_classification
+///
+/// # Examples
+///
+/// ```
+/// use classify::breaks_to_classification_float;
+/// use classify::{Classification, Bin};
+///
+/// let data: Vec<f32> = vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0];
+/// let breaks: Vec<f32> = vec![2.0, 5.0];
+///
+/// let result: Classification<f32> = breaks_to_classification_float(&breaks, &data);
+/// let expected: Classification<f32> = vec![
+///     Bin{bin_start: 1.0, bin_end: 2.0, count: 1},
+///     Bin{bin_start: 2.0, bin_end: 5.0, count: 2},
+///     Bin{bin_start: 5.0, bin_end: 8.0, count: 3}
+/// ];
+///
+/// assert!(result == expected);
+/// ```
+pub fn breaks_to_classification_float<F: Float>(breaks: &[F], data: &[F]) -> Classification<F> {
     let mut min_value = data[0];
     let mut max_value = data[0];
-    for item in &data {
+    for item in data {
         if *item < min_value {
             min_value = *item;
         }
@@ -128,13 +392,22 @@ pub fn breaks_to_classification<T: ToPrimitive>(breaks: &Vec<f64>, data: &[T]) -
         }
     }
 
-    let mut bounds: Vec<f64> = vec![min_value];
+    // Constant data has no range to split, so every value belongs to a single bin on that value
+    if min_value == max_value {
+        return vec![Bin {
+            bin_start: min_value,
+            bin_end: max_value,
+            count: data.len() as u64,
+        }];
+    }
+
+    let mut bounds: Vec<F> = vec![min_value];
     for item in breaks {
         bounds.push(*item);
     }
     bounds.push(max_value);
 
-    let mut results: Classification = vec![];
+    let mut results: Classification<F> = vec![];
     for i in 0..(bounds.len() - 1) {
         results.push(Bin {
             bin_start: bounds[i],
@@ -143,15 +416,18 @@ pub fn breaks_to_classification<T: ToPrimitive>(breaks: &Vec<f64>, data: &[T]) -
         });
     }
 
-    let num_bins = results.len();
-    for bin in results.iter_mut().take(num_bins) {
-        for item in &data {
-            if bin.bin_start <= *item && *item < bin.bin_end {
+    // Every bin contains its start but not its end, except for the last bin, which also contains its end (the maximum)
+    let last_bin = results.len() - 1;
+    for item in data {
+        for (i, bin) in results.iter_mut().enumerate() {
+            if bin.bin_start <= *item
+                && (*item < bin.bin_end || (i == last_bin && *item <= bin.bin_end))
+            {
                 bin.count += 1;
+                break;
             }
         }
     }
-    results[num_bins - 1].count += 1;
 
     results
 }
@@ -4,7 +4,9 @@ use wasm_bindgen::prelude::*;
 
 #[derive(Serialize, Deserialize)]
 pub struct JSBin {
+    #[serde(deserialize_with = "crate::utilities::deserialize_or_neg_infinity")]
     pub bin_start: f64,
+    #[serde(deserialize_with = "crate::utilities::deserialize_or_infinity")]
     pub bin_end: f64,
     pub count: u64,
 }
@@ -82,8 +84,7 @@ impl From<&Bin> for JSBin {
 
 #[wasm_bindgen]
 pub fn breaks_to_classification(breaks: &[f64], data: &[f64]) -> JsValue {
-    let class: JSClassification =
-        crate::utilities::breaks_to_classification(&breaks.to_vec(), data).into();
+    let class: JSClassification = crate::utilities::breaks_to_classification(breaks, data).into();
     JsValue::from_serde(&class).unwrap()
 }
 
@@ -106,6 +107,18 @@ pub fn get_jenks_classification(no_bins: usize, data: &[f64]) -> JsValue {
     JsValue::from_serde(&class).unwrap()
 }
 
+/// Classifies data with Jenks Natural Breaks, dropping NaN and infinite values (which JS arrays often contain) before classifying
+///
+/// Throws if no finite values remain or num_bins is 0
+#[wasm_bindgen]
+pub fn jenks(num_bins: usize, data: &[f64]) -> Result<JsValue, JsValue> {
+    let config = crate::jenks::JenksConfig::new().nan_policy(crate::utilities::NanPolicy::Drop);
+    match config.classify(num_bins, data) {
+        Ok(class) => Ok(JsValue::from_serde(&JSClassification::from(class)).unwrap()),
+        Err(err) => Err(JsValue::from_str(&err.to_string())),
+    }
+}
+
 #[wasm_bindgen]
 pub fn get_quantile_breaks(no_bins: usize, data: &[f64]) -> Box<[f64]> {
     let breaks = crate::quantile::get_quantile_breaks(no_bins, data);
@@ -169,3 +182,160 @@ pub fn get_hinge_classification(hinge_coefficient: f64, data: &[f64]) -> JsValue
         crate::hinge::get_hinge_classification(hinge_coefficient, data).into();
     JsValue::from_serde(&class).unwrap()
 }
+
+#[wasm_bindgen]
+pub fn get_geometric_interval_breaks(no_bins: usize, data: &[f64]) -> Box<[f64]> {
+    let breaks = crate::geometric_interval::get_geometric_interval_breaks(no_bins, data);
+    breaks.into_boxed_slice()
+}
+
+#[wasm_bindgen]
+pub fn get_geometric_interval_classification(no_bins: usize, data: &[f64]) -> JsValue {
+    let class: JSClassification =
+        crate::geometric_interval::get_geometric_interval_classification(no_bins, data).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_arithmetic_interval_breaks(no_bins: usize, data: &[f64]) -> Box<[f64]> {
+    let breaks = crate::arithmetic_interval::get_arithmetic_interval_breaks(no_bins, data);
+    breaks.into_boxed_slice()
+}
+
+#[wasm_bindgen]
+pub fn get_arithmetic_interval_classification(no_bins: usize, data: &[f64]) -> JsValue {
+    let class: JSClassification =
+        crate::arithmetic_interval::get_arithmetic_interval_classification(no_bins, data).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_fisher_jenks_breaks(no_bins: usize, data: &[f64]) -> Box<[f64]> {
+    let breaks = crate::fisher_jenks::get_fisher_jenks_breaks(no_bins, data);
+    breaks.into_boxed_slice()
+}
+
+#[wasm_bindgen]
+pub fn get_fisher_jenks_classification(no_bins: usize, data: &[f64]) -> JsValue {
+    let class: JSClassification =
+        crate::fisher_jenks::get_fisher_jenks_classification(no_bins, data).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_maximum_breaks(no_bins: usize, data: &[f64]) -> Box<[f64]> {
+    let breaks = crate::maximum_breaks::get_maximum_breaks(no_bins, data);
+    breaks.into_boxed_slice()
+}
+
+#[wasm_bindgen]
+pub fn get_maximum_breaks_classification(no_bins: usize, data: &[f64]) -> JsValue {
+    let class: JSClassification =
+        crate::maximum_breaks::get_maximum_breaks_classification(no_bins, data).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_pretty_breaks(no_bins: usize, data: &[f64]) -> Box<[f64]> {
+    let breaks = crate::pretty::get_pretty_breaks(no_bins, data);
+    breaks.into_boxed_slice()
+}
+
+#[wasm_bindgen]
+pub fn get_pretty_classification(no_bins: usize, data: &[f64]) -> JsValue {
+    let class: JSClassification = crate::pretty::get_pretty_classification(no_bins, data).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_manual_classification(breaks: &[f64], data: &[f64]) -> Result<JsValue, JsValue> {
+    match crate::manual::get_manual_classification(breaks, data) {
+        Ok(class) => Ok(JsValue::from_serde(&JSClassification::from(class)).unwrap()),
+        Err(err) => Err(JsValue::from_str(&err.to_string())),
+    }
+}
+
+#[wasm_bindgen]
+pub fn get_boxplot_classification(data: &[f64]) -> JsValue {
+    let class: JSClassification = crate::hinge::get_boxplot_classification(data).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_kmeans_breaks(no_bins: usize, data: &[f64], max_iter: usize) -> Box<[f64]> {
+    let breaks = crate::kmeans::get_kmeans_breaks(no_bins, data, max_iter);
+    breaks.into_boxed_slice()
+}
+
+#[wasm_bindgen]
+pub fn get_kmeans_classification(no_bins: usize, data: &[f64], max_iter: usize) -> JsValue {
+    let class: JSClassification =
+        crate::kmeans::get_kmeans_classification(no_bins, data, max_iter).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_percentile_classification(
+    percentiles: &[f64],
+    data: &[f64],
+) -> Result<JsValue, JsValue> {
+    let interpolation = crate::percentile::PercentileInterpolation::Linear;
+    match crate::percentile::get_percentile_classification(percentiles, data, interpolation) {
+        Ok(class) => Ok(JsValue::from_serde(&JSClassification::from(class)).unwrap()),
+        Err(err) => Err(JsValue::from_str(&err.to_string())),
+    }
+}
+
+#[wasm_bindgen]
+pub fn get_logarithmic_breaks(no_bins: usize, data: &[f64]) -> Result<Box<[f64]>, JsValue> {
+    match crate::logarithmic::get_logarithmic_breaks(no_bins, data) {
+        Ok(breaks) => Ok(breaks.into_boxed_slice()),
+        Err(err) => Err(JsValue::from_str(&err.to_string())),
+    }
+}
+
+#[wasm_bindgen]
+pub fn get_logarithmic_classification(no_bins: usize, data: &[f64]) -> Result<JsValue, JsValue> {
+    match crate::logarithmic::get_logarithmic_classification(no_bins, data) {
+        Ok(class) => Ok(JsValue::from_serde(&JSClassification::from(class)).unwrap()),
+        Err(err) => Err(JsValue::from_str(&err.to_string())),
+    }
+}
+
+#[wasm_bindgen]
+pub fn get_unique_value_classification(data: &[f64], max_classes: Option<usize>) -> JsValue {
+    let class: JSClassification =
+        crate::unique_value::get_unique_value_classification(data, max_classes).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_ckmeans_breaks(no_bins: usize, data: &[f64]) -> Box<[f64]> {
+    let breaks = crate::ckmeans::get_ckmeans_breaks(no_bins, data);
+    breaks.into_boxed_slice()
+}
+
+#[wasm_bindgen]
+pub fn get_ckmeans_classification(no_bins: usize, data: &[f64]) -> JsValue {
+    let class: JSClassification = crate::ckmeans::get_ckmeans_classification(no_bins, data).into();
+    JsValue::from_serde(&class).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_defined_interval_breaks(interval: f64, data: &[f64]) -> Result<Box<[f64]>, JsValue> {
+    match crate::defined_interval::get_defined_interval_breaks(interval, data) {
+        Ok(breaks) => Ok(breaks.into_boxed_slice()),
+        Err(err) => Err(JsValue::from_str(&err.to_string())),
+    }
+}
+
+#[wasm_bindgen]
+pub fn get_defined_interval_classification(
+    interval: f64,
+    data: &[f64],
+) -> Result<JsValue, JsValue> {
+    match crate::defined_interval::get_defined_interval_classification(interval, data) {
+        Ok(class) => Ok(JsValue::from_serde(&JSClassification::from(class)).unwrap()),
+        Err(err) => Err(JsValue::from_str(&err.to_string())),
+    }
+}